@@ -0,0 +1,51 @@
+use std::future::Future;
+use std::path::Path;
+
+use crate::{AppPath, AppPathError};
+
+impl AppPath {
+    /// Creates a path with an override computed asynchronously.
+    ///
+    /// Awaits `override_fn()` and resolves its result like [`Self::try_with_override_fn()`]:
+    /// `Some(path)` is used as the override, `None` falls back to `default`. Useful when
+    /// startup code fetches the location from a remote config service and must not block.
+    ///
+    /// The future is polled by whatever executor awaits this method; no particular
+    /// runtime is required.
+    ///
+    /// Requires the `tokio` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError`] if the executable location cannot be determined.
+    /// See [`Self::try_with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// async fn fetch_data_dir() -> Option<String> {
+    ///     // e.g. query a config service
+    ///     None
+    /// }
+    ///
+    /// async fn data_dir() -> Result<AppPath, AppPathError> {
+    ///     AppPath::with_override_async("data", fetch_data_dir).await
+    /// }
+    /// ```
+    pub async fn with_override_async<F, Fut, P>(
+        default: impl AsRef<Path>,
+        override_fn: F,
+    ) -> Result<Self, AppPathError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Option<P>>,
+        P: AsRef<Path>,
+    {
+        match override_fn().await {
+            Some(override_path) => Self::try_with(override_path),
+            None => Self::try_with(default),
+        }
+    }
+}
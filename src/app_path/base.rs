@@ -0,0 +1,409 @@
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+
+use crate::functions::{canonicalize_exe_dir, fnv1a_64};
+use crate::{try_exe_dir, AppPath, AppPathError};
+
+impl AppPath {
+    /// Returns how many path components lie below the application's base directory.
+    ///
+    /// The base directory itself has a count of `0`, a direct child `1`, and so on.
+    /// `.` components are ignored and `..` components step back up, so the count reflects
+    /// the effective depth. Returns `None` when the path lies outside the base directory
+    /// (an absolute override elsewhere, or `..` escaping above the base).
+    ///
+    /// This is cheaper than building the relative path when only the depth matters,
+    /// e.g. when enforcing a maximum nesting depth. The check is purely lexical and
+    /// never touches the filesystem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// assert_eq!(AppPath::new().relative_component_count(), Some(0));
+    /// assert_eq!(AppPath::with("config.toml").relative_component_count(), Some(1));
+    /// assert_eq!(AppPath::with("data/users/1.json").relative_component_count(), Some(3));
+    /// assert_eq!(AppPath::with("../outside.txt").relative_component_count(), None);
+    /// ```
+    pub fn relative_component_count(&self) -> Option<usize> {
+        let mut depth = 0usize;
+        for component in self.relative_to_base()?.components() {
+            match component {
+                Component::Normal(_) => depth += 1,
+                Component::CurDir => {}
+                Component::ParentDir => depth = depth.checked_sub(1)?,
+                Component::RootDir | Component::Prefix(_) => return None,
+            }
+        }
+        Some(depth)
+    }
+
+    /// Returns a key for this path that is safe as a file name or URL segment.
+    ///
+    /// - **In-base paths** produce `rel_` followed by the lexically normalized,
+    ///   forward-slash relative path, percent-encoded. The key is therefore stable across
+    ///   installations and platforms: `data/users.db` always maps to `rel_data%2Fusers.db`.
+    /// - **Out-of-base paths** (and non-UTF-8 in-base paths) produce `abs_` followed by a
+    ///   16-digit hex hash of the absolute path, so they can never collide with
+    ///   in-base keys.
+    ///
+    /// Keys contain only ASCII letters, digits, `-`, `.`, `_`, `~` and `%`; never a path
+    /// separator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let key = AppPath::with("cache/thumbnails/photo 1.png").storage_key();
+    /// assert_eq!(key, "rel_cache%2Fthumbnails%2Fphoto%201.png");
+    /// ```
+    pub fn storage_key(&self) -> String {
+        let relative = self.normalized_base_components().and_then(|components| {
+            components
+                .iter()
+                .map(|component| component.to_str())
+                .collect::<Option<Vec<_>>>()
+        });
+
+        match relative {
+            Some(components) => format!("rel_{}", percent_encode(&components.join("/"))),
+            None => format!("abs_{:016x}", fnv1a_64(&self.to_bytes())),
+        }
+    }
+
+    /// Renders this path relative to `root`, prefixed with a bracketed label.
+    ///
+    /// Useful for tools that manage several roots (e.g. a plugin directory and a data
+    /// directory) and want listings like `[data] users/1.json`. The relative part always
+    /// uses forward slashes; the root itself renders as `[label] .`.
+    ///
+    /// Returns `None` when this path is not under `root`. The check is purely lexical
+    /// and never touches the filesystem.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let data = AppPath::with("data");
+    /// let plugins = AppPath::with("plugins");
+    /// let user = AppPath::with("data/users/1.json");
+    ///
+    /// assert_eq!(
+    ///     user.relative_display_with_root(&data, "data"),
+    ///     Some("[data] users/1.json".to_string())
+    /// );
+    /// assert_eq!(user.relative_display_with_root(&plugins, "plugins"), None);
+    /// ```
+    pub fn relative_display_with_root(&self, root: &AppPath, label: &str) -> Option<String> {
+        let relative = self.full_path.strip_prefix(&root.full_path).ok()?;
+        let components: Vec<_> = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect();
+
+        if components.is_empty() {
+            Some(format!("[{label}] ."))
+        } else {
+            Some(format!("[{label}] {}", components.join("/")))
+        }
+    }
+
+    /// Returns the portion of this path below `root`, or an error if it is not under it.
+    ///
+    /// Use this where containment is an invariant and a path outside the expected root
+    /// indicates a bug. `.` and `..` components after the root are resolved lexically,
+    /// so `root/a/../b` yields `b` while `root/../x` is rejected. The root itself yields
+    /// an empty path. The filesystem is never touched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::PathEscapesBase`] if this path does not lie under `root`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    /// use std::path::Path;
+    ///
+    /// let data = AppPath::with("data");
+    ///
+    /// let user = AppPath::with("data/users/1.json");
+    /// assert_eq!(user.require_relative_to(&data)?, Path::new("users/1.json"));
+    ///
+    /// let log = AppPath::with("logs/app.log");
+    /// assert!(matches!(
+    ///     log.require_relative_to(&data),
+    ///     Err(AppPathError::PathEscapesBase { .. })
+    /// ));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn require_relative_to(&self, root: impl AsRef<Path>) -> Result<PathBuf, AppPathError> {
+        let root = root.as_ref();
+        let escapes = || AppPathError::PathEscapesBase {
+            path: self.full_path.clone(),
+            base: root.to_path_buf(),
+        };
+
+        let relative = self.full_path.strip_prefix(root).map_err(|_| escapes())?;
+        let mut components = Vec::new();
+        for component in relative.components() {
+            match component {
+                Component::Normal(name) => components.push(name),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    components.pop().ok_or_else(escapes)?;
+                }
+                Component::RootDir | Component::Prefix(_) => return Err(escapes()),
+            }
+        }
+        Ok(components.iter().collect())
+    }
+
+    /// Returns `true` if this path lies strictly inside `ancestor`.
+    ///
+    /// Both paths are normalized lexically first (`.` removed, `..` applied), so
+    /// `data/../secrets` is *not* a descendant of `data`, unlike a raw
+    /// [`Path::starts_with`] check. A path is not its own descendant. The check never
+    /// touches the filesystem, so symbolic links are not resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let data = AppPath::with("data");
+    ///
+    /// assert!(AppPath::with("data/users/1.json").is_descendant_of(&data));
+    /// assert!(!AppPath::with("data/../secrets.txt").is_descendant_of(&data));
+    /// assert!(!data.is_descendant_of(&data));
+    /// ```
+    pub fn is_descendant_of(&self, ancestor: &AppPath) -> bool {
+        let path = lexically_normalize(&self.full_path);
+        let ancestor = lexically_normalize(&ancestor.full_path);
+        path != ancestor && path.starts_with(&ancestor)
+    }
+
+    /// Returns `true` if `descendant` lies strictly inside this path.
+    ///
+    /// The inverse of [`Self::is_descendant_of()`], with the same lexical rules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let plugins = AppPath::with("plugins");
+    /// assert!(plugins.is_ancestor_of(&AppPath::with("plugins/audio/lib.so")));
+    /// assert!(!plugins.is_ancestor_of(&AppPath::with("plugins-old/lib.so")));
+    /// ```
+    pub fn is_ancestor_of(&self, descendant: &AppPath) -> bool {
+        descendant.is_descendant_of(self)
+    }
+
+    /// Returns `true` if this path lies inside the application's base directory.
+    ///
+    /// Use this to validate untrusted input such as override values before using them:
+    /// [`Self::with()`] passes absolute paths through and keeps `..` components, so
+    /// `../../etc/passwd` resolves outside the base. Both paths are normalized lexically
+    /// and compared component-wise, so a sibling like `app_backup` does not count as
+    /// inside `app`. The base directory itself counts as inside.
+    ///
+    /// The check never touches the filesystem, so it works for paths that do not exist
+    /// yet; symbolic links are not resolved. Returns `false` if the base directory cannot
+    /// be determined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// assert!(AppPath::with("plugins/audio.so").is_within_base());
+    /// assert!(!AppPath::with("../../etc/passwd").is_within_base());
+    /// ```
+    pub fn is_within_base(&self) -> bool {
+        let Ok(base) = try_exe_dir() else {
+            return false;
+        };
+        lexically_normalize(&self.full_path).starts_with(lexically_normalize(base))
+    }
+
+    /// Returns the part of this path below the application's base directory.
+    ///
+    /// Strips the base directory prefix, turning `/opt/app/data/users.db` back into
+    /// `data/users.db`, e.g. to write portable manifests that store only the relative
+    /// portion. The base directory itself yields an empty path. Returns `None` when the
+    /// path lies elsewhere, such as an absolute override pointing outside the base.
+    ///
+    /// The result is not normalized: `..` components are kept as written, so use
+    /// [`Self::try_with_contained()`] first when containment matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::path::Path;
+    ///
+    /// let db = AppPath::with("data/users.db");
+    /// assert_eq!(db.relative_to_base(), Some(Path::new("data/users.db")));
+    ///
+    /// let elsewhere = AppPath::with(std::env::temp_dir().join("users.db"));
+    /// # if !std::env::temp_dir().starts_with(AppPath::new()) {
+    /// assert_eq!(elsewhere.relative_to_base(), None);
+    /// # }
+    /// ```
+    pub fn relative_to_base(&self) -> Option<&Path> {
+        let base = try_exe_dir().ok()?;
+        self.full_path.strip_prefix(base).ok()
+    }
+
+    /// Iterates the components of this path below the application's base directory.
+    ///
+    /// Equivalent to `relative_to_base().map(Path::components)`, flattened: yields
+    /// nothing for the base directory itself or for a path outside it. Like
+    /// [`Self::relative_to_base()`], `.` and `..` components are not resolved; see
+    /// [`Self::relative_component_count()`] for the normalized depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::path::Component;
+    ///
+    /// let avatar = AppPath::with("users/42/avatar.png");
+    /// let segments: Vec<_> = avatar
+    ///     .components_relative()
+    ///     .map(Component::as_os_str)
+    ///     .collect();
+    /// assert_eq!(segments, ["users", "42", "avatar.png"]);
+    /// ```
+    pub fn components_relative(&self) -> impl Iterator<Item = Component<'_>> {
+        self.relative_to_base()
+            .into_iter()
+            .flat_map(Path::components)
+    }
+
+    /// Returns the normal components below the base after lexically resolving `.` and `..`.
+    ///
+    /// Returns `None` if the path lies outside the base directory.
+    pub(crate) fn normalized_base_components(&self) -> Option<Vec<&OsStr>> {
+        let mut components = Vec::new();
+        for component in self.relative_to_base()?.components() {
+            match component {
+                Component::Normal(name) => components.push(name),
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    components.pop()?;
+                }
+                Component::RootDir | Component::Prefix(_) => return None,
+            }
+        }
+        Some(components)
+    }
+
+    /// Renders this path relative to the base directory, for concise log messages.
+    ///
+    /// Paths inside the base show only the part below it (`data/users.db`), and the base
+    /// itself shows as `.`. Paths outside the base, such as an absolute override pointing
+    /// elsewhere or `..` escaping above the base, fall back to the full absolute display,
+    /// so the output is never ambiguous. [`Display`](std::fmt::Display) is unchanged and
+    /// always shows the absolute path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::path::Path;
+    ///
+    /// let db = AppPath::with("data/users.db");
+    /// assert_eq!(Path::new(&db.display_relative()), Path::new("data/users.db"));
+    /// assert_eq!(AppPath::new().display_relative(), ".");
+    ///
+    /// let escaped = AppPath::with("../shared/users.db");
+    /// assert_eq!(escaped.display_relative(), escaped.display().to_string());
+    /// ```
+    pub fn display_relative(&self) -> String {
+        match self.relative_to_base() {
+            Some(relative) if relative.as_os_str().is_empty() => ".".to_string(),
+            Some(relative) if self.is_within_base() => relative.display().to_string(),
+            _ => self.full_path.display().to_string(),
+        }
+    }
+
+    /// Switches the base directory to its canonical form, with symbolic links resolved.
+    ///
+    /// When the executable is reached through a symlinked directory (e.g. a symlinked
+    /// `/Applications` on macOS), the base directory keeps the symlinked spelling, and
+    /// comparisons against canonicalized input fail. Call this once at startup, before any
+    /// path is resolved: every later constructor and macro then produces canonical paths
+    /// without canonicalizing each one. On Windows the canonical form uses the verbatim
+    /// (`\\?\`) prefix, as with [`Self::canonicalize()`].
+    ///
+    /// Returns `Ok(true)` if the canonical directory is now the base. Calling it again is
+    /// harmless and keeps returning `Ok(true)`. Returns `Ok(false)`, and leaves resolution
+    /// unchanged, if the base was already determined in non-canonical form by an earlier
+    /// call to any constructor, since paths resolved before must stay valid. Like
+    /// [`Self::init_base_from_env()`], this is race-free against concurrent first use.
+    /// With the `testing` feature, an active `AppPath::set_exe_dir_for_testing()`
+    /// override is canonicalized instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the base directory cannot be determined (see
+    /// [`crate::try_exe_dir()`]), or [`AppPathError::IoError`] (including the path) if it
+    /// cannot be canonicalized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// // First thing in `main()`
+    /// AppPath::use_canonical_base()?;
+    /// let config = AppPath::with("config.toml");
+    /// assert_eq!(config.parent().unwrap(), AppPath::new().canonicalize()?);
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn use_canonical_base() -> Result<bool, AppPathError> {
+        canonicalize_exe_dir()
+    }
+}
+
+/// Removes `.` components and applies `..` components without touching the filesystem.
+///
+/// `..` never climbs above the root, matching how the OS resolves absolute paths.
+pub(crate) fn lexically_normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else if !normalized.has_root() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// Percent-encodes everything except RFC 3986 unreserved characters.
+fn percent_encode(input: &str) -> String {
+    let mut encoded = String::with_capacity(input.len());
+    for byte in input.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
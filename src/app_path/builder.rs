@@ -0,0 +1,223 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use super::constructors::non_blank;
+use crate::{AppPath, AppPathError};
+
+/// Builder for one-shot, fully configured path resolution.
+///
+/// Created by [`AppPath::builder()`]. Combines a default path, overrides, the choice of
+/// base directory and portability strictness in a single fluent call, dispatching to
+/// the same resolution rules as the `with_*` constructors.
+///
+/// # Precedence
+///
+/// 1. **Overrides**, in the order they were declared with [`Self::env()`] and
+///    [`Self::or_fn()`]. The first one that yields a path wins; later ones are not
+///    consulted.
+/// 2. **The default** from [`Self::default()`], or the base directory itself if no
+///    default was given.
+///
+/// The chosen path is then joined onto the base: the application's base directory
+/// unless [`Self::base()`] or [`Self::cwd_relative()`] picked another (the last of
+/// these calls wins). As everywhere in this crate, an absolute path replaces the base.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::AppPath;
+///
+/// let config = AppPath::builder()
+///     .default("config.toml")
+///     .env("MYAPP_CONFIG")
+///     .or_fn(|| None::<&str>)
+///     .portable(true)
+///     .try_build()?;
+/// # Ok::<(), app_path::AppPathError>(())
+/// ```
+pub struct AppPathBuilder {
+    default: PathBuf,
+    overrides: Vec<Override>,
+    base: Base,
+    portable: bool,
+}
+
+/// A source of an override, consulted in declaration order.
+enum Override {
+    Env(String),
+    Fn(Box<dyn FnOnce() -> Option<PathBuf>>),
+}
+
+/// The directory relative paths are joined onto.
+#[derive(Debug)]
+enum Base {
+    AppDir,
+    Cwd,
+    Dir(PathBuf),
+}
+
+impl fmt::Debug for AppPathBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let overrides: Vec<&dyn fmt::Debug> = self
+            .overrides
+            .iter()
+            .map(|source| match source {
+                Override::Env(var) => var as &dyn fmt::Debug,
+                Override::Fn(_) => &"<fn>",
+            })
+            .collect();
+        f.debug_struct("AppPathBuilder")
+            .field("default", &self.default)
+            .field("overrides", &overrides)
+            .field("base", &self.base)
+            .field("portable", &self.portable)
+            .finish()
+    }
+}
+
+impl AppPath {
+    /// Starts building a path with configurable resolution options.
+    ///
+    /// See [`AppPathBuilder`] for the available options and their precedence. Without
+    /// any overrides, `AppPath::builder().default(path).try_build()` is equivalent to
+    /// [`Self::try_with()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let data = AppPath::builder()
+    ///     .default("data")
+    ///     .env("MYAPP_DATA")
+    ///     .env("DATA_DIR")
+    ///     .build();
+    /// assert!(data.is_absolute());
+    /// ```
+    pub fn builder() -> AppPathBuilder {
+        AppPathBuilder {
+            default: PathBuf::new(),
+            overrides: Vec::new(),
+            base: Base::AppDir,
+            portable: false,
+        }
+    }
+}
+
+impl AppPathBuilder {
+    /// Sets the path used when no override applies.
+    ///
+    /// Defaults to an empty path, i.e. the base directory itself.
+    pub fn default(mut self, path: impl AsRef<Path>) -> Self {
+        self.default = path.as_ref().to_path_buf();
+        self
+    }
+
+    /// Adds an environment variable whose value overrides the default path.
+    ///
+    /// May be called several times and mixed with [`Self::or_fn()`]; overrides are
+    /// consulted in the order they were added and the first one that applies wins.
+    /// Empty or whitespace-only values are treated as unset, as in
+    /// [`AppPath::with_override()`].
+    pub fn env(mut self, var: impl Into<String>) -> Self {
+        let var = var.into();
+        crate::env_overrides::register_dynamic(&var);
+        self.overrides.push(Override::Env(var));
+        self
+    }
+
+    /// Adds a closure whose result overrides the default path when it returns `Some`.
+    ///
+    /// Consulted in declaration order together with [`Self::env()`]; the closure is not
+    /// called if an earlier override already applied.
+    pub fn or_fn<P, F>(mut self, override_fn: F) -> Self
+    where
+        P: AsRef<Path>,
+        F: FnOnce() -> Option<P> + 'static,
+    {
+        self.overrides.push(Override::Fn(Box::new(move || {
+            override_fn().map(|path| path.as_ref().to_path_buf())
+        })));
+        self
+    }
+
+    /// Resolves relative paths against `dir` instead of the application's base
+    /// directory, like [`AppPath::with_base()`].
+    ///
+    /// Replaces any earlier [`Self::base()`] or [`Self::cwd_relative()`] choice.
+    pub fn base(mut self, dir: impl AsRef<Path>) -> Self {
+        self.base = Base::Dir(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Resolves relative paths against the current working directory instead of the
+    /// application's base directory, like [`AppPath::from_cwd()`].
+    ///
+    /// Defaults to `false`. Replaces any earlier [`Self::base()`] or
+    /// [`Self::cwd_relative()`] choice; `false` restores the application's base
+    /// directory.
+    pub fn cwd_relative(mut self, cwd_relative: bool) -> Self {
+        self.base = if cwd_relative {
+            Base::Cwd
+        } else {
+            Base::AppDir
+        };
+        self
+    }
+
+    /// Rejects absolute paths, whether they come from the default or an override.
+    ///
+    /// When enabled, [`Self::try_build()`] returns [`AppPathError::AbsolutePathRejected`]
+    /// for absolute inputs regardless of the process-wide
+    /// [`AbsolutePolicy`](crate::AbsolutePolicy). Defaults to `false`.
+    pub fn portable(mut self, portable: bool) -> Self {
+        self.portable = portable;
+        self
+    }
+
+    /// Resolves the path using the configured options.
+    ///
+    /// See the [type documentation](AppPathBuilder) for precedence.
+    ///
+    /// # Panics
+    ///
+    /// Panics in any situation where [`Self::try_build()`] returns an error.
+    pub fn build(self) -> AppPath {
+        match self.try_build() {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to build AppPath: {e}"),
+        }
+    }
+
+    /// Resolves the path using the configured options (fallible).
+    ///
+    /// See the [type documentation](AppPathBuilder) for precedence.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::AbsolutePathRejected`] - The chosen path is absolute and
+    ///   [`Self::portable()`] is enabled (or rejected by the process-wide policy)
+    /// - [`AppPathError::IoError`] - The current working directory cannot be determined
+    /// - [`AppPathError::ExecutableNotFound`] / [`AppPathError::InvalidExecutablePath`] -
+    ///   The application's base directory cannot be determined
+    pub fn try_build(self) -> Result<AppPath, AppPathError> {
+        let path = self
+            .overrides
+            .into_iter()
+            .find_map(|source| match source {
+                Override::Env(var) => non_blank(std::env::var_os(var)).map(PathBuf::from),
+                Override::Fn(override_fn) => override_fn(),
+            })
+            .unwrap_or(self.default);
+
+        if self.portable && path.is_absolute() {
+            return Err(AppPathError::AbsolutePathRejected(path));
+        }
+
+        match self.base {
+            Base::AppDir => AppPath::try_with(path),
+            Base::Cwd => AppPath::try_from_cwd(path),
+            Base::Dir(dir) => AppPath::try_with_base(dir, path),
+        }
+    }
+}
@@ -1,6 +1,11 @@
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
 use std::path::Path;
 
-use crate::{try_exe_dir, AppPath, AppPathError};
+use super::base::lexically_normalize;
+use crate::functions::try_exe_file;
+use crate::policy::check_absolute_input;
+use crate::{env_overrides, try_exe_dir, AppPath, AppPathError, ResolutionSource};
 
 impl AppPath {
     /// Returns the application's base directory as an AppPath.
@@ -231,6 +236,55 @@ impl AppPath {
         })
     }
 
+    /// Returns the path of the running executable **file**.
+    ///
+    /// Where [`Self::new()`] returns the *directory* containing the executable, this
+    /// returns the executable itself (e.g. `/opt/myapp/myapp` or `C:\Apps\myapp.exe`),
+    /// as reported by [`std::env::current_exe()`]. Useful for self-updaters and logging.
+    /// The value is cached after the first call, sharing the lookup with the base
+    /// directory, so repeated calls are cheap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the executable location cannot be determined. See
+    /// [`Self::try_exe_file()`] for the fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let exe = AppPath::exe_file();
+    /// println!("Running {}", exe.display());
+    /// assert!(exe.is_file());
+    /// ```
+    #[inline]
+    pub fn exe_file() -> Self {
+        match Self::try_exe_file() {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to determine executable file: {e}"),
+        }
+    }
+
+    /// Returns the path of the running executable **file** (fallible).
+    ///
+    /// The fallible counterpart of [`Self::exe_file()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::ExecutableNotFound`] - `std::env::current_exe()` fails (extremely rare)
+    /// - [`AppPathError::InvalidExecutablePath`] - Executable path is empty (system corruption)
+    ///
+    /// After the first successful call, the path is cached and this method will never
+    /// return an error.
+    #[inline]
+    pub fn try_exe_file() -> Result<Self, AppPathError> {
+        let exe_file = try_exe_file()?;
+        Ok(Self {
+            full_path: exe_file.to_path_buf(),
+        })
+    }
+
     /// Creates file paths relative to the application's base directory (fallible).
     ///
     /// **Use this only for libraries or specialized applications requiring explicit error handling.**
@@ -271,10 +325,30 @@ impl AppPath {
     /// ```
     ///
     /// After the first successful call, the application's base directory is cached and this method
-    /// will never return an error.
+    /// only fails for invalid input.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::ExecutableNotFound`] / [`AppPathError::InvalidExecutablePath`] -
+    ///   The application's base directory cannot be determined
+    /// - [`AppPathError::InvalidPath`] - `path` contains an interior NUL byte, which no
+    ///   filesystem accepts
+    /// - [`AppPathError::AbsolutePathRejected`] - `path` is absolute and
+    ///   [`AbsolutePolicy::Reject`](crate::AbsolutePolicy::Reject) is in effect
+    ///
+    /// The `cache` feature does little for this method: an `AppPath` owns its `PathBuf`,
+    /// so every call still allocates and copies the cached path, saving only the
+    /// reallocation a join may need. Use
+    /// [`AppPathShared::with()`](crate::AppPathShared::with) in hot loops, which hands out
+    /// the cached path without allocating.
     #[inline]
     pub fn try_with(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
         let exe_dir = try_exe_dir()?;
+        let path = path.as_ref();
+        check_input(path)?;
+        #[cfg(feature = "cache")]
+        let full_path = crate::cache::resolve(exe_dir, path).to_path_buf();
+        #[cfg(not(feature = "cache"))]
         let full_path = exe_dir.join(path);
         Ok(Self { full_path })
     }
@@ -302,7 +376,8 @@ impl AppPath {
     /// ## Path Resolution
     ///
     /// - **Relative paths**: Resolved relative to application's base directory
-    /// - **Absolute paths**: Used as-is (not recommended - defeats portability)
+    /// - **Absolute paths**: Used as-is (not recommended - defeats portability), subject to
+    ///   the process-wide [`AbsolutePolicy`](crate::AbsolutePolicy)
     /// - **Path separators**: Automatically normalized for current platform
     ///
     /// ## Global Caching Behavior
@@ -314,7 +389,8 @@ impl AppPath {
     ///
     /// Panics only if the application's base directory cannot be determined, which is extremely rare
     /// and typically indicates fundamental system issues (corrupted installation, permission problems).
-    /// After the first successful call, this method never panics.
+    /// After the first successful call, it only panics for input that [`Self::try_with()`]
+    /// rejects, such as a path containing an interior NUL byte, with the same message.
     ///
     /// # Arguments
     ///
@@ -396,6 +472,273 @@ impl AppPath {
         }
     }
 
+    /// Creates a path, expanding a leading `~` to the user's home directory.
+    ///
+    /// Lets users write values such as `LOG_PATH=~/logs/app.log`. A path that is
+    /// exactly `~` or starts with `~/` (or `~\` on Windows) has the `~` replaced by
+    /// `$HOME` on Unix or `%USERPROFILE%` on Windows; the result is then resolved like
+    /// [`Self::with()`]. Any other path, including `~file` or a `~` later in the path,
+    /// is passed to [`Self::with()`] unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if expansion is needed but the home variable is not set, or for any
+    /// reason [`Self::with()`] would. See [`Self::try_with_tilde()`] for the fallible
+    /// version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let log = AppPath::with_tilde("~/logs/app.log");
+    /// # #[cfg(unix)]
+    /// # if let Some(home) = std::env::var_os("HOME") {
+    /// #     assert_eq!(&*log, std::path::Path::new(&home).join("logs/app.log"));
+    /// # }
+    ///
+    /// // No leading `~`: normal resolution
+    /// assert_eq!(AppPath::with_tilde("~file"), AppPath::with("~file"));
+    /// ```
+    #[inline]
+    pub fn with_tilde(path: impl AsRef<Path>) -> Self {
+        match Self::try_with_tilde(path) {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to create AppPath: {e}"),
+        }
+    }
+
+    /// Creates a path, expanding a leading `~` to the user's home directory (fallible).
+    ///
+    /// The fallible counterpart of [`Self::with_tilde()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::IoError`] ([`std::io::ErrorKind::NotFound`]) - The path starts
+    ///   with `~` but the home variable (`HOME` or `USERPROFILE`) is not set
+    /// - Any error from [`Self::try_with()`]
+    #[inline]
+    pub fn try_with_tilde(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let home = std::env::var_os(HOME_VAR);
+        let expanded = expand_tilde(path.as_ref(), home.as_deref())?;
+        Self::try_with(expanded)
+    }
+
+    /// Creates a path after substituting environment variables in it.
+    ///
+    /// Supports `$VAR` and `${VAR}` everywhere, plus `%VAR%` on Windows, so deployment
+    /// descriptors can contain paths like `$DATA_ROOT/db/users.db`. Write `$$` for a
+    /// literal `$`. A `$` not followed by a variable name is kept as-is. The expanded
+    /// string is then resolved like [`Self::with()`], so a variable holding an absolute
+    /// path produces an absolute result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a referenced variable is unset or the syntax is invalid. See
+    /// [`Self::try_with_expanded()`] for the fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// std::env::set_var("MYAPP_DB_DIR", "db");
+    /// let users = AppPath::with_expanded("${MYAPP_DB_DIR}/users.db");
+    /// assert_eq!(users, AppPath::with("db/users.db"));
+    ///
+    /// let price = AppPath::with_expanded("prices/$$5.txt");
+    /// assert_eq!(price, AppPath::with("prices/$5.txt"));
+    /// ```
+    #[inline]
+    pub fn with_expanded(path: &str) -> Self {
+        match Self::try_with_expanded(path) {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to create AppPath: {e}"),
+        }
+    }
+
+    /// Creates a path after substituting environment variables in it (fallible).
+    ///
+    /// The fallible counterpart of [`Self::with_expanded()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::IoError`] ([`std::io::ErrorKind::NotFound`]) - A referenced
+    ///   variable is unset or not valid Unicode
+    /// - [`AppPathError::IoError`] ([`std::io::ErrorKind::InvalidInput`]) - A `${` has
+    ///   no closing `}`
+    /// - Any error from [`Self::try_with()`]
+    #[inline]
+    pub fn try_with_expanded(path: &str) -> Result<Self, AppPathError> {
+        let expanded = expand_env_vars(path, cfg!(windows), |var| std::env::var(var).ok())?;
+        Self::try_with(expanded)
+    }
+
+    /// Creates a path relative to an explicit base directory.
+    ///
+    /// Works like [`Self::with()`] but joins `path` onto `base` instead of the
+    /// application's base directory, e.g. for a plugin host where each plugin has its
+    /// own root. An absolute `path` replaces `base`, exactly as with [`Self::with()`].
+    ///
+    /// `base` is used as given: a relative `base` produces a relative path, resolved
+    /// later against the current working directory by the OS. The executable location
+    /// is never consulted.
+    ///
+    /// # Panics
+    ///
+    /// Panics only if `path` is absolute and [`AbsolutePolicy::Reject`](crate::AbsolutePolicy::Reject)
+    /// is in effect. See [`Self::try_with_base()`] for the fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let plugin_root = AppPath::with("plugins/audio");
+    /// let settings = AppPath::with_base(&plugin_root, "settings.toml");
+    /// assert_eq!(settings, AppPath::with("plugins/audio/settings.toml"));
+    /// ```
+    #[inline]
+    pub fn with_base(base: impl AsRef<Path>, path: impl AsRef<Path>) -> Self {
+        match Self::try_with_base(base, path) {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to create AppPath: {e}"),
+        }
+    }
+
+    /// Creates a path relative to an explicit base directory (fallible).
+    ///
+    /// The fallible counterpart of [`Self::with_base()`]. Since the executable location
+    /// is never consulted, this cannot fail to determine a base directory.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::InvalidPath`] - `path` contains an interior NUL byte
+    /// - [`AppPathError::AbsolutePathRejected`] - `path` is absolute and
+    ///   [`AbsolutePolicy::Reject`](crate::AbsolutePolicy::Reject) is in effect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let data = AppPath::try_with_base(std::env::temp_dir(), "myapp/data")?;
+    /// assert_eq!(&*data, std::env::temp_dir().join("myapp/data"));
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    #[inline]
+    pub fn try_with_base(
+        base: impl AsRef<Path>,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, AppPathError> {
+        let path = path.as_ref();
+        check_input(path)?;
+        let full_path = base.as_ref().join(path);
+        Ok(Self { full_path })
+    }
+
+    /// Creates a path that is guaranteed to lie inside the application's base directory.
+    ///
+    /// Resolves `path` like [`Self::try_with()`], then normalizes the result lexically
+    /// (`.` removed, `..` applied) and rejects it if it is not under the base directory.
+    /// Use this to build paths from untrusted input such as config values or plugin
+    /// manifests without checking [`Self::is_within_base()`] by hand. The returned path
+    /// is the normalized one. The filesystem is never touched, so symbolic links are not
+    /// resolved.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::PathEscapesBase`] - The normalized path is outside the base
+    ///   directory, whether through `..` traversal or an absolute input elsewhere
+    /// - Any error from [`Self::try_with()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// let plugin = AppPath::try_with_contained("plugins/audio/../video/lib.so")?;
+    /// assert_eq!(plugin, AppPath::with("plugins/video/lib.so"));
+    ///
+    /// assert!(matches!(
+    ///     AppPath::try_with_contained("../../etc/passwd"),
+    ///     Err(AppPathError::PathEscapesBase { .. })
+    /// ));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn try_with_contained(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let resolved = Self::try_with(path)?;
+        let base = try_exe_dir()?;
+        let full_path = lexically_normalize(&resolved.full_path);
+        if !full_path.starts_with(lexically_normalize(base)) {
+            return Err(AppPathError::PathEscapesBase {
+                path: resolved.full_path,
+                base: base.to_path_buf(),
+            });
+        }
+        Ok(Self { full_path })
+    }
+
+    /// Creates a path relative to the current working directory.
+    ///
+    /// For CLI subcommands that intentionally act on paths relative to where the user
+    /// invoked the tool rather than next to the binary. Absolute inputs pass through
+    /// untouched, exactly as with [`Self::with()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the current working directory cannot be determined (e.g. it was
+    /// deleted) or the absolute-path policy rejects `path`. See [`Self::try_from_cwd()`]
+    /// for the fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let output = AppPath::from_cwd("report.csv");
+    /// assert_eq!(&*output, std::env::current_dir()?.join("report.csv"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[inline]
+    pub fn from_cwd(path: impl AsRef<Path>) -> Self {
+        match Self::try_from_cwd(path) {
+            Ok(app_path) => app_path,
+            Err(e) => panic!("Failed to create AppPath: {e}"),
+        }
+    }
+
+    /// Creates a path relative to the current working directory (fallible).
+    ///
+    /// The fallible counterpart of [`Self::from_cwd()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::IoError`] - [`std::env::current_dir()`] fails
+    /// - [`AppPathError::InvalidPath`] - `path` contains an interior NUL byte
+    /// - [`AppPathError::AbsolutePathRejected`] - `path` is absolute and
+    ///   [`AbsolutePolicy::Reject`](crate::AbsolutePolicy::Reject) is in effect
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// fn output_path(arg: &str) -> Result<AppPath, AppPathError> {
+    ///     AppPath::try_from_cwd(arg)
+    /// }
+    /// # assert!(output_path("out.txt").is_ok());
+    /// ```
+    #[inline]
+    pub fn try_from_cwd(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let path = path.as_ref();
+        check_input(path)?;
+        let cwd = std::env::current_dir()?;
+        Ok(Self {
+            full_path: cwd.join(path),
+        })
+    }
+
     /// Creates a path with override support (infallible).
     ///
     /// This method provides a one-line solution for creating paths that can be overridden
@@ -416,6 +759,11 @@ impl AppPath {
     /// **If override is provided**: Use the override path directly (can be relative or absolute)
     /// **If override is `None`**: Use the default path with normal AppPath resolution
     ///
+    /// An empty or whitespace-only override is treated as `None`, so an exported but
+    /// blank environment variable falls back to the default instead of resolving to the
+    /// base directory itself. Use [`Self::with_override_allow_empty()`] to keep such
+    /// values.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -446,6 +794,27 @@ impl AppPath {
     pub fn with_override(
         default: impl AsRef<Path>,
         override_option: Option<impl AsRef<Path>>,
+    ) -> Self {
+        Self::with_override_allow_empty(default, non_blank(override_option))
+    }
+
+    /// Creates a path with override support, using empty overrides as-is.
+    ///
+    /// Like [`Self::with_override()`], except that an empty or whitespace-only override
+    /// is not treated as `None`: an empty override resolves to the base directory itself.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let base = AppPath::with_override_allow_empty("config.toml", Some(""));
+    /// assert_eq!(base, AppPath::new());
+    /// ```
+    #[inline]
+    pub fn with_override_allow_empty(
+        default: impl AsRef<Path>,
+        override_option: Option<impl AsRef<Path>>,
     ) -> Self {
         match override_option {
             Some(override_path) => Self::with(override_path),
@@ -503,6 +872,9 @@ impl AppPath {
     /// **Fallible version of [`Self::with_override()`].** Most applications should use the
     /// infallible version instead for cleaner code.
     ///
+    /// Empty or whitespace-only overrides are treated as `None`, as in
+    /// [`Self::with_override()`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -571,6 +943,23 @@ impl AppPath {
     pub fn try_with_override(
         default: impl AsRef<Path>,
         override_option: Option<impl AsRef<Path>>,
+    ) -> Result<Self, AppPathError> {
+        Self::try_with_override_allow_empty(default, non_blank(override_option))
+    }
+
+    /// Creates a path with override support, using empty overrides as-is (fallible).
+    ///
+    /// **Fallible version of [`Self::with_override_allow_empty()`].**
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError`] if the executable location cannot be determined:
+    /// - [`AppPathError::ExecutableNotFound`] - `std::env::current_exe()` fails (extremely rare)
+    /// - [`AppPathError::InvalidExecutablePath`] - Executable path is empty (system corruption)
+    #[inline]
+    pub fn try_with_override_allow_empty(
+        default: impl AsRef<Path>,
+        override_option: Option<impl AsRef<Path>>,
     ) -> Result<Self, AppPathError> {
         match override_option {
             Some(override_path) => Self::try_with(override_path),
@@ -665,4 +1054,441 @@ impl AppPath {
             None => Self::try_with(default),
         }
     }
+
+    /// Creates a path with a fallible override lookup.
+    ///
+    /// Like [`Self::try_with_override_fn()`], but `override_fn` can fail, e.g. when the
+    /// override is read from a keyring or a remote store. This keeps "no override
+    /// configured" (`Ok(None)`, falls back to `default`) apart from "the lookup failed"
+    /// (`Err`, returned to the caller instead of silently using `default`).
+    ///
+    /// # Errors
+    ///
+    /// - The error returned by `override_fn`, converted into [`AppPathError`]
+    /// - Any error from [`Self::try_with()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    /// use std::io;
+    ///
+    /// fn lookup_override() -> io::Result<Option<String>> {
+    ///     Ok(None) // e.g. query a keyring
+    /// }
+    ///
+    /// let data = AppPath::try_with_override_result_fn("data", lookup_override)?;
+    /// assert_eq!(data, AppPath::with("data"));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    #[inline]
+    pub fn try_with_override_result_fn<P, E>(
+        default: impl AsRef<Path>,
+        override_fn: impl FnOnce() -> Result<Option<P>, E>,
+    ) -> Result<Self, AppPathError>
+    where
+        P: AsRef<Path>,
+        E: Into<AppPathError>,
+    {
+        match override_fn().map_err(Into::into)? {
+            Some(override_path) => Self::try_with(override_path),
+            None => Self::try_with(default),
+        }
+    }
+
+    /// Creates a path overridable by the first of several environment variables.
+    ///
+    /// The variables are checked in order and the first one that is set to a non-blank
+    /// value is used as the path; otherwise `default` is used. Empty or whitespace-only
+    /// values are treated as unset, as in [`Self::with_override()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with_override_envs("config.toml", &["MYAPP_CONFIG", "CONFIG_PATH"]);
+    /// ```
+    #[inline]
+    pub fn with_override_envs(default: impl AsRef<Path>, vars: &[&str]) -> Self {
+        Self::with_override(default, first_env_override(vars).map(|(_, value)| value))
+    }
+
+    /// Creates a path with override support and reports which source was used.
+    ///
+    /// Resolves exactly like [`Self::with_override()`] (blank overrides count as `None`)
+    /// and additionally returns [`ResolutionSource::Override`] or
+    /// [`ResolutionSource::Default`], so "which config am I actually using?" becomes a
+    /// one-line log.
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, ResolutionSource};
+    ///
+    /// let cli_config: Option<&str> = None;
+    /// let (config, source) = AppPath::with_override_traced("config.toml", cli_config);
+    /// assert_eq!(source, ResolutionSource::Default);
+    /// println!("Using {config} ({source:?})");
+    /// ```
+    pub fn with_override_traced(
+        default: impl AsRef<Path>,
+        override_option: Option<impl AsRef<Path>>,
+    ) -> (Self, ResolutionSource) {
+        match non_blank(override_option) {
+            Some(override_path) => (Self::with(override_path), ResolutionSource::Override),
+            None => (Self::with(default), ResolutionSource::Default),
+        }
+    }
+
+    /// Creates a path overridable by several environment variables and reports which
+    /// one won.
+    ///
+    /// Resolves exactly like [`Self::with_override_envs()`] and additionally returns
+    /// [`ResolutionSource::EnvOverride`] with the name of the variable that was used, or
+    /// [`ResolutionSource::Default`].
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, ResolutionSource};
+    ///
+    /// let (config, source) =
+    ///     AppPath::with_override_envs_traced("config.toml", &["MYAPP_CONFIG", "CONFIG_PATH"]);
+    /// match source {
+    ///     ResolutionSource::EnvOverride(var) => println!("{config} (set by {var})"),
+    ///     _ => println!("{config} (default)"),
+    /// }
+    /// ```
+    pub fn with_override_envs_traced(
+        default: impl AsRef<Path>,
+        vars: &[&str],
+    ) -> (Self, ResolutionSource) {
+        match first_env_override(vars) {
+            Some((var, value)) => (
+                Self::with(value),
+                ResolutionSource::EnvOverride(var.to_string()),
+            ),
+            None => (Self::with(default), ResolutionSource::Default),
+        }
+    }
+
+    /// Creates a path overridable by the first of several environment variables (fallible).
+    ///
+    /// **Fallible version of [`Self::with_override_envs()`].** Most applications should use
+    /// the infallible version instead for cleaner code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError`] if the executable location cannot be determined:
+    /// - [`AppPathError::ExecutableNotFound`] - `std::env::current_exe()` fails (extremely rare)
+    /// - [`AppPathError::InvalidExecutablePath`] - Executable path is empty (system corruption)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// fn config_path() -> Result<AppPath, AppPathError> {
+    ///     AppPath::try_with_override_envs("config.toml", &["MYAPP_CONFIG", "CONFIG_PATH"])
+    /// }
+    /// ```
+    #[inline]
+    pub fn try_with_override_envs(
+        default: impl AsRef<Path>,
+        vars: &[&str],
+    ) -> Result<Self, AppPathError> {
+        Self::try_with_override(default, first_env_override(vars).map(|(_, value)| value))
+    }
+
+    /// Creates a path and verifies that its parent directory already exists.
+    ///
+    /// The strict counterpart of [`Self::create_parents()`]: instead of creating missing
+    /// directories, it fails early so misconfigured locations are caught before any
+    /// write is attempted. Resolution follows [`Self::try_with()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::ParentNotFound`] - The parent directory does not exist (or is
+    ///   not a directory)
+    /// - Any error from [`Self::try_with()`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// // The application directory always exists
+    /// let log = AppPath::with_existing_parent("app.log")?;
+    ///
+    /// match AppPath::with_existing_parent("not/created/yet.txt") {
+    ///     Err(AppPathError::ParentNotFound(dir)) => eprintln!("Missing {}", dir.display()),
+    ///     other => panic!("unexpected: {other:?}"),
+    /// }
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn with_existing_parent(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let resolved = Self::try_with(path)?;
+        match resolved.full_path.parent() {
+            Some(parent) if !parent.is_dir() => {
+                Err(AppPathError::ParentNotFound(parent.to_path_buf()))
+            }
+            _ => Ok(resolved),
+        }
+    }
+
+    /// Resolves several paths at once, reporting which source each one used.
+    ///
+    /// Each spec is `(default, env_var)`. When `env_var` is set to a non-blank value, it
+    /// is used as the override, exactly like `app_path!(default, env = env_var)`;
+    /// otherwise the default is resolved normally. Results keep the order of `specs`.
+    ///
+    /// The variables are recorded in [`Self::registered_env_overrides()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, ResolutionSource};
+    ///
+    /// let resolved = AppPath::resolve_reported(&[
+    ///     ("config.toml", "MYAPP_CONFIG"),
+    ///     ("data", "MYAPP_DATA_DIR"),
+    /// ]);
+    ///
+    /// println!("Using:");
+    /// for (path, source) in &resolved {
+    ///     match source {
+    ///         ResolutionSource::EnvOverride(var) => println!("  {path} (set by {var})"),
+    ///         _ => println!("  {path}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_reported(specs: &[(&str, &str)]) -> Vec<(AppPath, ResolutionSource)> {
+        specs
+            .iter()
+            .map(|&(default, env_var)| Self::with_override_envs_traced(default, &[env_var]))
+            .collect()
+    }
+
+    /// Finds `file` in the directories listed by a search-path environment variable.
+    ///
+    /// The variable is split like `PATH` (`:` on Unix, `;` on Windows, see
+    /// [`std::env::split_paths`]). `file` is joined onto each directory in order and
+    /// the first existing match is returned. Relative directories are resolved against
+    /// the application's base directory; empty entries are skipped.
+    ///
+    /// Returns `None` if the variable is unset or no directory contains `file`. The
+    /// variable is recorded in [`Self::registered_env_overrides()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// // MYAPP_PLUGIN_PATH=plugins:/usr/share/myapp/plugins
+    /// match AppPath::from_search_path("MYAPP_PLUGIN_PATH", "audio.so") {
+    ///     Some(plugin) => println!("Loading {}", plugin.display()),
+    ///     None => println!("Plugin not found"),
+    /// }
+    /// ```
+    pub fn from_search_path(var: &str, file: &str) -> Option<AppPath> {
+        env_overrides::register_dynamic(var);
+        let dirs = std::env::var_os(var)?;
+        std::env::split_paths(&dirs)
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .filter_map(|dir| Self::try_with(dir).ok())
+            .map(|dir| dir.join(file))
+            .find(|candidate| candidate.exists())
+    }
+
+    /// Creates a path from an [`OsStr`] relative to the application's base directory.
+    ///
+    /// This is the explicit entry point for paths that arrive as OS strings, such as
+    /// values from [`std::env::args_os()`] or buffers built from raw bytes over FFI.
+    /// No UTF-8 conversion is performed, so non-UTF-8 paths are preserved exactly.
+    /// Resolution follows the same rules as [`Self::with()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::ffi::OsStr;
+    ///
+    /// let config = AppPath::from_os_str(OsStr::new("config.toml"));
+    /// assert_eq!(config, AppPath::with("config.toml"));
+    /// ```
+    #[inline]
+    pub fn from_os_str(s: &OsStr) -> Self {
+        Self::with(s)
+    }
+
+    /// Creates a path from an owned [`OsString`] relative to the application's base directory.
+    ///
+    /// Owned counterpart of [`Self::from_os_str()`]. Useful when the OS string was
+    /// assembled from raw platform bytes (e.g. `OsStringExt::from_vec` on Unix).
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::ffi::OsString;
+    ///
+    /// let arg = std::env::args_os().next().unwrap_or_else(|| OsString::from("app"));
+    /// let path = AppPath::from_os_string(arg);
+    /// assert!(path.is_absolute());
+    /// ```
+    #[inline]
+    pub fn from_os_string(s: OsString) -> Self {
+        Self::with(s)
+    }
+}
+
+/// Environment variable holding the user's home directory.
+#[cfg(windows)]
+const HOME_VAR: &str = "USERPROFILE";
+#[cfg(not(windows))]
+const HOME_VAR: &str = "HOME";
+
+/// Validates a constructor input before it is joined onto a base directory.
+///
+/// Interior NUL bytes are rejected up front, since the OS would refuse the path only
+/// later, when it is first used. Absolute inputs are then subject to the current policy.
+pub(crate) fn check_input(path: &Path) -> Result<(), AppPathError> {
+    if path.as_os_str().as_encoded_bytes().contains(&0) {
+        return Err(AppPathError::InvalidPath {
+            path: path.to_path_buf(),
+            reason: "contains an interior NUL byte".to_string(),
+        });
+    }
+    check_absolute_input(path)
+}
+
+/// Registers `vars` and returns the first one set to a non-blank value, with that value.
+fn first_env_override<'a>(vars: &[&'a str]) -> Option<(&'a str, OsString)> {
+    for &var in vars {
+        env_overrides::register_dynamic(var);
+    }
+    vars.iter()
+        .find_map(|&var| non_blank(std::env::var_os(var)).map(|value| (var, value)))
+}
+
+/// Treats an empty or whitespace-only override as absent.
+pub(crate) fn non_blank<P: AsRef<Path>>(override_option: Option<P>) -> Option<P> {
+    override_option.filter(|path| {
+        !path
+            .as_ref()
+            .as_os_str()
+            .to_string_lossy()
+            .trim()
+            .is_empty()
+    })
+}
+
+/// Replaces a leading `~` component with `home`; other paths are returned unchanged.
+pub(crate) fn expand_tilde<'a>(
+    path: &'a Path,
+    home: Option<&OsStr>,
+) -> Result<Cow<'a, Path>, AppPathError> {
+    let Ok(rest) = path.strip_prefix("~") else {
+        return Ok(Cow::Borrowed(path));
+    };
+    match home {
+        Some(home) if !home.is_empty() && rest.as_os_str().is_empty() => {
+            Ok(Cow::Owned(Path::new(home).to_path_buf()))
+        }
+        Some(home) if !home.is_empty() => Ok(Cow::Owned(Path::new(home).join(rest))),
+        _ => Err(AppPathError::IoError(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!(
+                "cannot expand `~` in {}: {HOME_VAR} is not set",
+                path.display()
+            ),
+        ))),
+    }
+}
+
+/// Substitutes `$VAR`, `${VAR}` and (when `percent` is set) `%VAR%` using `lookup`.
+pub(crate) fn expand_env_vars(
+    input: &str,
+    percent: bool,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String, AppPathError> {
+    let resolve = |name: &str| {
+        lookup(name).ok_or_else(|| {
+            AppPathError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("environment variable {name} referenced in {input} is not set"),
+            ))
+        })
+    };
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(c) = rest.chars().next() {
+        if c == '$' {
+            let after = &rest[1..];
+            if let Some(after) = after.strip_prefix('$') {
+                output.push('$');
+                rest = after;
+            } else if let Some(braced) = after.strip_prefix('{') {
+                let end = braced.find('}').ok_or_else(|| {
+                    AppPathError::IoError(std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("unterminated ${{ in {input}"),
+                    ))
+                })?;
+                output.push_str(&resolve(&braced[..end])?);
+                rest = &braced[end + 1..];
+            } else if after.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+                output.push_str(&resolve(&after[..end])?);
+                rest = &after[end..];
+            } else {
+                output.push('$');
+                rest = after;
+            }
+        } else if c == '%' && percent {
+            let after = &rest[1..];
+            match after.find('%') {
+                Some(end) if end > 0 && after[..end].chars().all(is_name_char) => {
+                    output.push_str(&resolve(&after[..end])?);
+                    rest = &after[end + 1..];
+                }
+                _ => {
+                    output.push('%');
+                    rest = after;
+                }
+            }
+        } else {
+            output.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    Ok(output)
 }
@@ -1,4 +1,6 @@
-use crate::{AppPath, AppPathError};
+use std::path::{Path, PathBuf};
+
+use crate::{AppPath, AppPathError, PathKind, PathMatcher};
 
 impl AppPath {
     /// Creates parent directories needed for this file path.
@@ -70,14 +72,104 @@ impl AppPath {
     ///
     /// The operation is **not atomic** - some parent directories may be created even if the
     /// operation ultimately fails.
+    ///
+    /// The error message names the directory that could not be created and its deepest
+    /// existing ancestor (see [`Self::existing_ancestor()`]).
     #[inline]
     pub fn create_parents(&self) -> Result<(), AppPathError> {
         if let Some(parent) = self.full_path.parent() {
-            std::fs::create_dir_all(parent)?;
+            std::fs::create_dir_all(parent).map_err(|e| creation_error(e, parent))?;
         }
         Ok(())
     }
 
+    /// Creates the parent directories and returns `self`, for fluent setup code.
+    ///
+    /// The chainable form of [`Self::create_parents()`], with the same behavior and errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{app_path, AppPath};
+    ///
+    /// let log = app_path!("parent_created_example/logs/app.log").parent_created()?;
+    /// assert!(log.parent().unwrap().is_dir());
+    /// # std::fs::remove_dir_all(AppPath::with("parent_created_example")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    #[inline]
+    pub fn parent_created(self) -> Result<Self, AppPathError> {
+        self.create_parents()?;
+        Ok(self)
+    }
+
+    /// Returns the canonical form of this path, with all symbolic links resolved.
+    ///
+    /// Same as [`Path::canonicalize`], but keeps the result as an `AppPath` so method
+    /// chains like `config.canonicalize()?.parent()` keep working. The path must exist.
+    /// The result is always absolute; on Windows it uses the verbatim (`\\?\`) form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the path does not exist
+    /// or a component cannot be resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let exe_dir = AppPath::new().canonicalize()?;
+    /// assert!(exe_dir.is_absolute());
+    /// assert!(exe_dir.parent().is_some());
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn canonicalize(&self) -> Result<AppPath, AppPathError> {
+        let full_path = std::fs::canonicalize(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        Ok(AppPath { full_path })
+    }
+
+    /// Creates the parent directories, then returns the parent's canonical path.
+    ///
+    /// Combines [`Self::create_parents()`] with [`std::fs::canonicalize`], for APIs that
+    /// demand a real, existing directory with all symbolic links resolved. On Windows
+    /// the result uses the verbatim (`\\?\`) form returned by `canonicalize`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if:
+    /// - This path has no parent, i.e. it is a filesystem root
+    ///   ([`std::io::ErrorKind::InvalidInput`])
+    /// - The parent directories cannot be created or canonicalized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let db = AppPath::with("canonical_parent_example/db/app.sqlite");
+    /// let dir = db.canonical_parent()?;
+    /// assert!(dir.is_dir());
+    /// assert_eq!(dir, AppPath::with(std::fs::canonicalize(&dir)?));
+    /// # std::fs::remove_dir_all(AppPath::with("canonical_parent_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonical_parent(&self) -> Result<AppPath, AppPathError> {
+        let parent = self.full_path.parent().ok_or_else(|| {
+            let err = std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "path has no parent directory",
+            );
+            AppPathError::from((err, &self.full_path))
+        })?;
+        std::fs::create_dir_all(parent).map_err(|e| creation_error(e, parent))?;
+
+        let full_path = std::fs::canonicalize(parent)
+            .map_err(|e| AppPathError::from((e, &parent.to_path_buf())))?;
+        Ok(AppPath { full_path })
+    }
+
     /// Creates this path as a directory, including all parent directories.
     ///
     /// This method treats the path as a directory and creates it along with
@@ -199,9 +291,561 @@ impl AppPath {
     ///
     /// The operation creates parent directories as needed, but is **not atomic** - some
     /// parent directories may be created even if the final directory creation fails.
+    ///
+    /// The error message names the directory that could not be created and its deepest
+    /// existing ancestor (see [`Self::existing_ancestor()`]).
     #[inline]
     pub fn create_dir(&self) -> Result<(), AppPathError> {
-        std::fs::create_dir_all(self)?;
+        std::fs::create_dir_all(self).map_err(|e| creation_error(e, &self.full_path))?;
         Ok(())
     }
+
+    /// Creates this path as a directory and returns `self`, for fluent setup code.
+    ///
+    /// The chainable form of [`Self::create_dir()`], with the same behavior and errors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{app_path, AppPath};
+    ///
+    /// let cache = app_path!("dir_created_example/cache").dir_created()?;
+    /// assert!(cache.is_dir());
+    /// # std::fs::remove_dir_all(AppPath::with("dir_created_example")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    #[inline]
+    pub fn dir_created(self) -> Result<Self, AppPathError> {
+        self.create_dir()?;
+        Ok(self)
+    }
+
+    /// Removes the file at this path.
+    ///
+    /// Delegates to [`std::fs::remove_file()`], adding the path to any error. Counterpart
+    /// of [`Self::create_parents()`] for cleanup routines; use
+    /// [`Self::remove_if_exists()`] for idempotent teardown.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the file does not
+    /// exist, is a directory, or cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let lock = AppPath::with("remove_file_example.lock");
+    /// lock.write("pid")?;
+    /// lock.remove_file()?;
+    /// assert!(!lock.exists());
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn remove_file(&self) -> Result<(), AppPathError> {
+        std::fs::remove_file(&self.full_path).map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Removes the directory at this path along with all of its contents.
+    ///
+    /// Delegates to [`std::fs::remove_dir_all()`], adding the path to any error.
+    /// Counterpart of [`Self::create_dir()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the directory does not
+    /// exist, is not a directory, or any of its contents cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let cache = AppPath::with("remove_dir_all_example/cache");
+    /// cache.join("thumbs").create_dir()?;
+    /// cache.remove_dir_all()?;
+    /// assert!(!cache.exists());
+    /// # std::fs::remove_dir_all(AppPath::with("remove_dir_all_example")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn remove_dir_all(&self) -> Result<(), AppPathError> {
+        std::fs::remove_dir_all(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Removes whatever is at this path, treating a missing path as success.
+    ///
+    /// Directories are removed with their contents as in [`Self::remove_dir_all()`];
+    /// anything else (including a symbolic link, which is not followed) is removed as in
+    /// [`Self::remove_file()`]. Running the same teardown twice is therefore a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the path exists but
+    /// cannot be removed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let scratch = AppPath::with("remove_if_exists_example");
+    /// scratch.create_dir()?;
+    /// scratch.remove_if_exists()?;
+    /// scratch.remove_if_exists()?; // Already gone: still Ok
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn remove_if_exists(&self) -> Result<(), AppPathError> {
+        let result = match std::fs::symlink_metadata(&self.full_path) {
+            Ok(metadata) if metadata.is_dir() => self.remove_dir_all(),
+            Ok(_) => self.remove_file(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(AppPathError::from((e, &self.full_path))),
+        };
+        match result {
+            // Removed concurrently in the meantime
+            Err(AppPathError::IoError(e)) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Ensures an expected directory layout exists below the application directory.
+    ///
+    /// Each entry is resolved like [`Self::try_with()`]. [`PathKind::Dir`] entries are
+    /// created with [`Self::create_dir()`]; [`PathKind::File`] entries only get their
+    /// parents created with [`Self::create_parents()`]. Existing entries are left
+    /// untouched, so running the same layout again is a no-op.
+    ///
+    /// Returns the resolved paths in the order of `spec`.
+    ///
+    /// # Errors
+    ///
+    /// Every entry is attempted even if an earlier one fails. If any fail, returns a
+    /// single [`AppPathError::IoError`] listing each failed entry with its reason; the
+    /// error kind is that of the first failure. Entries that succeeded stay created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, PathKind};
+    ///
+    /// // First-run scaffolding
+    /// let layout = [
+    ///     (PathKind::Dir, "data/cache"),
+    ///     (PathKind::Dir, "logs"),
+    ///     (PathKind::File, "config/app.toml"),
+    /// ];
+    /// let paths = AppPath::ensure_layout(&layout)?;
+    /// assert!(paths[0].is_dir());
+    /// assert!(!paths[2].exists()); // Only `config/` was created
+    /// # std::fs::remove_dir_all(AppPath::with("data")).ok();
+    /// # std::fs::remove_dir_all(AppPath::with("logs")).ok();
+    /// # std::fs::remove_dir_all(AppPath::with("config")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn ensure_layout(spec: &[(PathKind, &str)]) -> Result<Vec<AppPath>, AppPathError> {
+        let mut paths = Vec::with_capacity(spec.len());
+        let mut failures = Vec::new();
+
+        for &(kind, entry) in spec {
+            let result = AppPath::try_with(entry).and_then(|path| {
+                match kind {
+                    PathKind::Dir => path.create_dir()?,
+                    PathKind::File => path.create_parents()?,
+                }
+                Ok(path)
+            });
+            match result {
+                Ok(path) => paths.push(path),
+                Err(err) => failures.push((entry, err)),
+            }
+        }
+
+        if failures.is_empty() {
+            return Ok(paths);
+        }
+
+        let kind = match &failures[0].1 {
+            AppPathError::IoError(err) => err.kind(),
+            _ => std::io::ErrorKind::Other,
+        };
+        let details: Vec<String> = failures
+            .iter()
+            .map(|(entry, err)| format!("{entry}: {err}"))
+            .collect();
+        let msg = format!(
+            "failed to ensure {} of {} layout entries: {}",
+            failures.len(),
+            spec.len(),
+            details.join("; ")
+        );
+        Err(AppPathError::IoError(std::io::Error::new(kind, msg)))
+    }
+
+    /// Returns the immediate subdirectories of this directory, sorted by path.
+    ///
+    /// Symbolic links are followed, so a link to a directory is included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the directory cannot be
+    /// read, e.g. because it does not exist or is a file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// for dir in AppPath::new().subdirs()? {
+    ///     println!("[dir] {}", dir.display());
+    /// }
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn subdirs(&self) -> Result<Vec<AppPath>, AppPathError> {
+        self.children_where(Path::is_dir)
+    }
+
+    /// Returns the immediate files in this directory, sorted by path.
+    ///
+    /// Symbolic links are followed, so a link to a file is included.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the directory cannot be
+    /// read, e.g. because it does not exist or is a file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let configs: Vec<_> = AppPath::new()
+    ///     .files()?
+    ///     .into_iter()
+    ///     .filter(|file| file.extension().is_some_and(|ext| ext == "toml"))
+    ///     .collect();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn files(&self) -> Result<Vec<AppPath>, AppPathError> {
+        self.children_where(Path::is_file)
+    }
+
+    /// Recursively finds entries below this directory that match a glob pattern.
+    ///
+    /// The pattern is matched against each entry's path relative to this directory,
+    /// using the syntax of [`PathMatcher`] (`*`, `?` and `**`). Both files and
+    /// directories can match. Symbolic links are reported but never descended into.
+    ///
+    /// Returns the matches sorted by path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if this directory or any
+    /// directory below it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let plugins = AppPath::with("plugins");
+    /// # plugins.join("audio").create_dir()?;
+    /// # std::fs::write(plugins.join("audio/config.toml"), "")?;
+    /// for config in plugins.glob("*/config.toml")? {
+    ///     println!("Loading {}", config.display());
+    /// }
+    /// # std::fs::remove_dir_all(&plugins).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn glob(&self, pattern: &str) -> Result<Vec<AppPath>, AppPathError> {
+        let matcher = PathMatcher::new(pattern);
+        let mut matches = Vec::new();
+        let mut prefix = Vec::new();
+        glob_walk(&self.full_path, &matcher, &mut prefix, &mut matches)?;
+        matches.sort();
+        Ok(matches)
+    }
+
+    /// Lists every file below this directory for packaging into an archive.
+    ///
+    /// Returns `(name, path)` pairs where `name` is the file's path relative to this
+    /// directory with `/` separators, ready to use as a zip or tar entry name, and
+    /// `path` is the absolute location to read from. Entries are sorted by name.
+    ///
+    /// Only regular files are listed; directories are implied by the names and
+    /// symbolic links are skipped. Leftover artifacts of this crate's own helpers are
+    /// skipped too: temporary files from [`Self::temp_sibling()`] and `<name>.lock`
+    /// files next to an existing `<name>` (see [`Self::increment_counter()`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if this directory or any
+    /// directory below it cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let dist = AppPath::with("bundle_entries_example");
+    /// dist.join("assets").create_dir()?;
+    /// std::fs::write(dist.join("assets/logo.png"), b"png")?;
+    ///
+    /// for (name, path) in dist.bundle_entries()? {
+    ///     println!("{name} <- {}", path.display()); // assets/logo.png <- /.../assets/logo.png
+    /// }
+    /// # std::fs::remove_dir_all(&dist).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bundle_entries(&self) -> Result<Vec<(String, AppPath)>, AppPathError> {
+        let mut entries = Vec::new();
+        let mut pending = vec![(self.full_path.clone(), String::new())];
+
+        while let Some((dir, prefix)) = pending.pop() {
+            let read_dir = std::fs::read_dir(&dir).map_err(|e| AppPathError::from((e, &dir)))?;
+            for entry in read_dir {
+                let entry = entry.map_err(|e| AppPathError::from((e, &dir)))?;
+                let path = entry.path();
+                let file_type = entry
+                    .file_type()
+                    .map_err(|e| AppPathError::from((e, &path)))?;
+                let file_name = entry.file_name().to_string_lossy().into_owned();
+                let name = format!("{prefix}{file_name}");
+
+                if file_type.is_dir() {
+                    pending.push((path, format!("{name}/")));
+                } else if file_type.is_file() && !is_helper_artifact(&path, &file_name) {
+                    entries.push((name, AppPath { full_path: path }));
+                }
+            }
+        }
+
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(entries)
+    }
+
+    /// Counts all files and directories below this directory, recursively.
+    ///
+    /// This directory itself is not counted. Symbolic links are counted as entries but
+    /// never followed. Intended for sizing progress bars before a tree operation.
+    ///
+    /// Counting is best-effort below the top level: a subdirectory that cannot be read
+    /// (e.g. due to permissions) is counted itself, but its contents are skipped rather
+    /// than failing the whole count.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) only if this directory
+    /// itself cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let tree = AppPath::with("count_entries_example");
+    /// tree.join("sub").create_dir()?;
+    /// std::fs::write(tree.join("sub/file.txt"), "")?;
+    ///
+    /// assert_eq!(tree.count_entries()?, 2); // sub/ and sub/file.txt
+    /// assert_eq!(tree.count_files()?, 1);
+    /// # std::fs::remove_dir_all(&tree).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count_entries(&self) -> Result<usize, AppPathError> {
+        self.count_tree().map(|(entries, _)| entries)
+    }
+
+    /// Counts the files below this directory, recursively.
+    ///
+    /// Follows the same rules as [`Self::count_entries()`]; symbolic links are not
+    /// counted as files.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) only if this directory
+    /// itself cannot be read.
+    pub fn count_files(&self) -> Result<usize, AppPathError> {
+        self.count_tree().map(|(_, files)| files)
+    }
+
+    /// Returns `(entries, files)` for the subtree.
+    fn count_tree(&self) -> Result<(usize, usize), AppPathError> {
+        let entries = std::fs::read_dir(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+
+        let (mut total, mut files) = (0, 0);
+        let mut subdirs = Vec::new();
+        let mut count = |entries: std::fs::ReadDir, subdirs: &mut Vec<_>| {
+            for entry in entries.flatten() {
+                total += 1;
+                match entry.file_type() {
+                    Ok(file_type) if file_type.is_file() => files += 1,
+                    Ok(file_type) if file_type.is_dir() => subdirs.push(entry.path()),
+                    _ => {}
+                }
+            }
+        };
+
+        count(entries, &mut subdirs);
+        while let Some(dir) = subdirs.pop() {
+            if let Ok(entries) = std::fs::read_dir(dir) {
+                count(entries, &mut subdirs);
+            }
+        }
+        Ok((total, files))
+    }
+
+    fn children_where(&self, keep: fn(&Path) -> bool) -> Result<Vec<AppPath>, AppPathError> {
+        let entries = std::fs::read_dir(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+
+        let mut children = Vec::new();
+        for entry in entries {
+            let path = entry
+                .map_err(|e| AppPathError::from((e, &self.full_path)))?
+                .path();
+            if keep(&path) {
+                children.push(AppPath { full_path: path });
+            }
+        }
+        children.sort();
+        Ok(children)
+    }
+
+    /// Returns the deepest ancestor of this path that exists, including the path itself.
+    ///
+    /// Walks up from this path towards the filesystem root and returns the first entry
+    /// that exists, or `None` if nothing does. Useful for actionable diagnostics when
+    /// directory creation fails, e.g. "can't create `a/b/c`: `a` exists but is read-only".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let deep = AppPath::with("not/yet/created/file.txt");
+    /// let existing = deep.existing_ancestor().unwrap();
+    /// assert_eq!(existing, AppPath::new());
+    /// ```
+    pub fn existing_ancestor(&self) -> Option<AppPath> {
+        deepest_existing(&self.full_path).map(|ancestor| AppPath {
+            full_path: ancestor.to_path_buf(),
+        })
+    }
+
+    /// Searches this directory and its ancestors for `filename`.
+    ///
+    /// Starts at this path if it is an existing directory, otherwise at its parent, and
+    /// checks each level up to the filesystem root for `filename` with
+    /// [`Path::exists`]. Returns the first match, i.e. the one closest to this path.
+    /// Useful for project-root discovery, such as finding the nearest `.git` or
+    /// `config.toml` above the executable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// if let Some(config) = AppPath::new().find_upwards("config.toml") {
+    ///     println!("Using {}", config.display());
+    /// }
+    /// ```
+    pub fn find_upwards(&self, filename: impl AsRef<Path>) -> Option<AppPath> {
+        let filename = filename.as_ref();
+        let start = if self.full_path.is_dir() {
+            self.full_path.as_path()
+        } else {
+            self.full_path.parent()?
+        };
+        start
+            .ancestors()
+            .map(|dir| dir.join(filename))
+            .find(|candidate| candidate.exists())
+            .map(|full_path| AppPath { full_path })
+    }
+}
+
+fn deepest_existing(path: &Path) -> Option<&Path> {
+    path.ancestors()
+        .find(|ancestor| !ancestor.as_os_str().is_empty() && ancestor.exists())
+}
+
+/// Returns `true` for temp and lock files left behind by this crate's helpers.
+fn is_helper_artifact(path: &Path, file_name: &str) -> bool {
+    if AppPath::is_temp_sibling_name(file_name) {
+        return true;
+    }
+    file_name
+        .strip_suffix(".lock")
+        .is_some_and(|locked| !locked.is_empty() && path.with_file_name(locked).is_file())
+}
+
+fn glob_walk(
+    dir: &Path,
+    matcher: &PathMatcher,
+    prefix: &mut Vec<String>,
+    matches: &mut Vec<AppPath>,
+) -> Result<(), AppPathError> {
+    let dir_buf = dir.to_path_buf();
+    let entries = std::fs::read_dir(dir).map_err(|e| AppPathError::from((e, &dir_buf)))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| AppPathError::from((e, &dir_buf)))?;
+        let path = entry.path();
+        let is_dir = entry
+            .file_type()
+            .map_err(|e| AppPathError::from((e, &path)))?
+            .is_dir();
+
+        prefix.push(entry.file_name().to_string_lossy().into_owned());
+        if matcher.matches_components(prefix) {
+            matches.push(AppPath {
+                full_path: path.clone(),
+            });
+        }
+        if is_dir {
+            glob_walk(&path, matcher, prefix, matches)?;
+        }
+        prefix.pop();
+    }
+    Ok(())
+}
+
+/// Adds the failed directory and its deepest existing ancestor to a creation error.
+///
+/// The original error is kept as the [`source`](std::error::Error::source), so its
+/// `raw_os_error()` is still reachable through the error chain.
+fn creation_error(err: std::io::Error, dir: &Path) -> AppPathError {
+    let kind = err.kind();
+    let context = CreationError {
+        ancestor: deepest_existing(dir).map(Path::to_path_buf),
+        path: dir.to_path_buf(),
+        source: err,
+    };
+    AppPathError::IoError(std::io::Error::new(kind, context))
+}
+
+/// A failed directory creation, with the directory and its deepest existing ancestor.
+#[derive(Debug)]
+struct CreationError {
+    source: std::io::Error,
+    path: PathBuf,
+    ancestor: Option<PathBuf>,
+}
+
+impl std::fmt::Display for CreationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (path: {}", self.source, self.path.display())?;
+        if let Some(ancestor) = &self.ancestor {
+            write!(f, ", deepest existing ancestor: {}", ancestor.display())?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl std::error::Error for CreationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
 }
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use super::constructors::non_blank;
+use crate::functions::seed_exe_dir;
+use crate::{env_overrides, AppPath};
+
+impl AppPath {
+    /// Records an environment variable that can override a path in this application.
+    ///
+    /// The `env =` arms of [`app_path!`](macro@crate::app_path) and
+    /// [`try_app_path!`](crate::try_app_path), and [`AppPathBuilder::env()`](crate::AppPathBuilder::env),
+    /// register their variables automatically. Call this for variables consulted in
+    /// other ways (e.g. inside an override closure) so that
+    /// [`Self::registered_env_overrides()`] gives a complete picture.
+    ///
+    /// Registering the same variable again has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// AppPath::register_env_override("MYAPP_CACHE_DIR");
+    /// assert!(AppPath::registered_env_overrides().contains(&"MYAPP_CACHE_DIR"));
+    /// ```
+    pub fn register_env_override(var: &'static str) {
+        env_overrides::register(var);
+    }
+
+    /// Returns every environment variable registered as a path override so far.
+    ///
+    /// Variables appear once each, in the order they were first registered. Since
+    /// registration happens when an override is consulted, call this after the
+    /// application has resolved its paths, e.g. to implement a
+    /// `--print-env-overrides` flag or generate documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::app_path;
+    /// use app_path::AppPath;
+    ///
+    /// let _config = app_path!("config.toml", env = "MYAPP_CONFIG");
+    ///
+    /// for var in AppPath::registered_env_overrides() {
+    ///     println!("{var}");
+    /// }
+    /// # assert!(AppPath::registered_env_overrides().contains(&"MYAPP_CONFIG"));
+    /// ```
+    pub fn registered_env_overrides() -> Vec<&'static str> {
+        env_overrides::registered()
+    }
+
+    /// Uses the directory in environment variable `var` as the application's base
+    /// directory, instead of the executable's directory.
+    ///
+    /// Call this once at startup, before any path is resolved; for containers, e.g.
+    /// `APP_PATH_BASE=/data`. From then on every constructor and macro resolves against
+    /// that directory without changes at call sites. The variable is registered like
+    /// other overrides (see [`Self::registered_env_overrides()`]).
+    ///
+    /// Returns `true` if the variable's directory is now the base. Returns `false`, and
+    /// leaves resolution unchanged, when:
+    /// - the variable is unset, empty or whitespace-only, or not an absolute path
+    ///   (a relative base could not anchor portable paths)
+    /// - the base directory was already determined by an earlier call to any
+    ///   constructor, since paths resolved before must stay valid
+    ///
+    /// Seeding is race-free: if another thread resolves a path concurrently, exactly one
+    /// of the two directories is used everywhere. With the `testing` feature,
+    /// `AppPath::set_exe_dir_for_testing()` still takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// // First thing in `main()`
+    /// if AppPath::init_base_from_env("APP_PATH_BASE") {
+    ///     eprintln!("Using base directory from APP_PATH_BASE");
+    /// }
+    /// let config = AppPath::with("config.toml");
+    /// ```
+    pub fn init_base_from_env(var: &str) -> bool {
+        env_overrides::register_dynamic(var);
+        match non_blank(std::env::var_os(var)).map(PathBuf::from) {
+            Some(base) if base.is_absolute() => seed_exe_dir(base),
+            _ => false,
+        }
+    }
+}
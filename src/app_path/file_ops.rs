@@ -0,0 +1,858 @@
+use std::fs::{File, Metadata, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::{AppPath, AppPathError};
+
+/// How long to wait for an advisory lock before giving up.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How old a lock file must be before it is treated as abandoned by a holder that died.
+///
+/// Locks are held for a single read-write cycle, so this is far longer than any live
+/// holder keeps one. Until a lock reaches this age, waiters time out after
+/// [`LOCK_TIMEOUT`].
+const STALE_LOCK_AGE: Duration = Duration::from_secs(300);
+
+const BINARY_UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL_UNITS: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+impl AppPath {
+    /// Queries the metadata of the file or directory, following symbolic links.
+    ///
+    /// A thin wrapper over [`std::fs::metadata()`] that names the path in the error. It
+    /// shadows [`Path::metadata`], whose error carries no path.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the metadata cannot be
+    /// read, e.g. because the path does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let metadata = AppPath::new().metadata()?;
+    /// assert!(metadata.is_dir());
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn metadata(&self) -> Result<Metadata, AppPathError> {
+        std::fs::metadata(&self.full_path).map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Changes the permissions of the file or directory.
+    ///
+    /// A thin wrapper over [`std::fs::set_permissions()`] that names the path in the
+    /// error; symbolic links are followed as there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the path does not exist
+    /// or the permissions cannot be changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(unix)]
+    /// # {
+    /// use app_path::AppPath;
+    /// use std::os::unix::fs::PermissionsExt;
+    ///
+    /// let secrets = AppPath::with("set_permissions_example.key");
+    /// secrets.write("token")?;
+    /// secrets.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    /// # secrets.remove_file()?;
+    /// # }
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn set_permissions(&self, permissions: std::fs::Permissions) -> Result<(), AppPathError> {
+        std::fs::set_permissions(&self.full_path, permissions)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Sets or clears the read-only flag of the file or directory.
+    ///
+    /// Reads the current permissions, changes them with
+    /// [`Permissions::set_readonly()`](std::fs::Permissions::set_readonly) and writes them
+    /// back. On Unix, setting the flag removes every write bit, while clearing it restores
+    /// only the owner's write bit, so a file that was private stays private; use
+    /// [`Self::set_permissions()`] with an explicit mode for finer control.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the path does not exist
+    /// or the permissions cannot be read or changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let license = AppPath::with("set_readonly_example.txt");
+    /// license.write("read me")?;
+    /// license.set_readonly(true)?;
+    /// assert!(license.metadata()?.permissions().readonly());
+    /// # license.set_readonly(false)?;
+    /// # license.remove_file()?;
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn set_readonly(&self, readonly: bool) -> Result<(), AppPathError> {
+        let mut permissions = self.metadata()?.permissions();
+        #[cfg(unix)]
+        if !readonly {
+            use std::os::unix::fs::PermissionsExt;
+
+            // `Permissions::set_readonly(false)` would make the file world-writable
+            permissions.set_mode(permissions.mode() | 0o200);
+            return self.set_permissions(permissions);
+        }
+        permissions.set_readonly(readonly);
+        self.set_permissions(permissions)
+    }
+
+    /// Returns `true` if the path exists and is a regular file.
+    ///
+    /// Answers "exists and is a file" with a single `stat` call, so the two facts cannot
+    /// disagree because of a concurrent change in between. Symbolic links are followed:
+    /// a link to a file counts, a dangling link does not. Never errors; any I/O problem
+    /// (missing path, permission denied, ...) yields `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// assert!(!AppPath::new().exists_as_file());
+    /// assert!(AppPath::exe_file().exists_as_file());
+    /// ```
+    pub fn exists_as_file(&self) -> bool {
+        std::fs::metadata(&self.full_path).is_ok_and(|metadata| metadata.is_file())
+    }
+
+    /// Returns `true` if the path exists and is a directory.
+    ///
+    /// The directory counterpart of [`Self::exists_as_file()`], with the same single-call,
+    /// link-following and never-erroring behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// assert!(AppPath::new().exists_as_dir());
+    /// assert!(!AppPath::with("exists_as_dir_missing").exists_as_dir());
+    /// ```
+    pub fn exists_as_dir(&self) -> bool {
+        std::fs::metadata(&self.full_path).is_ok_and(|metadata| metadata.is_dir())
+    }
+
+    /// Returns `true` if the path is a file with at least one execute bit set (Unix only).
+    ///
+    /// Checks the owner, group and other execute bits alike, so it answers "is this
+    /// marked executable?" rather than "may the current user run it?". Directories
+    /// always return `false` even though their execute bit means "searchable". Symbolic
+    /// links are followed. Returns `false` if the path does not exist or cannot be
+    /// inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(unix)]
+    /// # {
+    /// use app_path::AppPath;
+    ///
+    /// let helper = AppPath::with("bin/helper");
+    /// if helper.is_executable() {
+    ///     std::process::Command::new(&helper).status()?;
+    /// }
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[cfg(unix)]
+    pub fn is_executable(&self) -> bool {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::metadata(&self.full_path)
+            .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+    }
+
+    /// Returns the last modification time, e.g. for cache invalidation.
+    ///
+    /// Shorthand for [`Self::metadata()`] followed by [`Metadata::modified()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the metadata cannot be
+    /// read or the platform does not record modification times.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let source = AppPath::with("modified_example.txt");
+    /// source.write("v1")?;
+    /// let stamp = source.modified()?;
+    /// assert!(stamp <= std::time::SystemTime::now());
+    /// # source.remove_file()?;
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn modified(&self) -> Result<SystemTime, AppPathError> {
+        self.metadata()?
+            .modified()
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Returns the size of the file in bytes.
+    ///
+    /// Shorthand for [`Self::metadata()`] followed by [`Metadata::len()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the metadata cannot be
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let blob = AppPath::with("len_example.bin");
+    /// blob.write([0u8; 42])?;
+    /// assert_eq!(blob.len()?, 42);
+    /// # blob.remove_file()?;
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    #[allow(clippy::len_without_is_empty)] // Size of the file, not of the path
+    pub fn len(&self) -> Result<u64, AppPathError> {
+        Ok(self.metadata()?.len())
+    }
+
+    /// Returns the file size formatted with binary units, e.g. `"1.40 MiB"`.
+    ///
+    /// Sizes below 1024 bytes are shown as whole bytes (`"1023 B"`); larger sizes use
+    /// two decimal places and the largest fitting unit (`"1.00 KiB"`). For decimal units
+    /// (`kB`, `MB`, ...) use [`Self::human_size_si()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the metadata cannot be
+    /// read, e.g. because the path does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let file = AppPath::with("human_size_example.bin");
+    /// std::fs::write(&file, vec![0u8; 1536])?;
+    /// assert_eq!(file.human_size()?, "1.50 KiB");
+    /// # std::fs::remove_file(&file).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn human_size(&self) -> Result<String, AppPathError> {
+        Ok(format_size(self.len()?, 1024, &BINARY_UNITS))
+    }
+
+    /// Returns the file size formatted with decimal (SI) units, e.g. `"1.47 MB"`.
+    ///
+    /// Decimal counterpart of [`Self::human_size()`], using powers of 1000.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the metadata cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let file = AppPath::with("human_size_si_example.bin");
+    /// std::fs::write(&file, vec![0u8; 1500])?;
+    /// assert_eq!(file.human_size_si()?, "1.50 kB");
+    /// # std::fs::remove_file(&file).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn human_size_si(&self) -> Result<String, AppPathError> {
+        Ok(format_size(self.len()?, 1000, &DECIMAL_UNITS))
+    }
+
+    /// Reads the entire file into a string.
+    ///
+    /// Equivalent to [`std::fs::read_to_string()`], but the error names the path that
+    /// failed, so config loading stays a single call with actionable errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the file cannot be read,
+    /// e.g. because it does not exist, is a directory, or is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("read_to_string_example.toml");
+    /// std::fs::write(&config, "port = 8080")?;
+    /// assert_eq!(config.read_to_string()?, "port = 8080");
+    /// # std::fs::remove_file(&config).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_to_string(&self) -> Result<String, AppPathError> {
+        std::fs::read_to_string(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Reads the entire file into a byte vector.
+    ///
+    /// Binary counterpart of [`Self::read_to_string()`], with the same path-aware errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the file cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let blob = AppPath::with("read_bytes_example.bin");
+    /// std::fs::write(&blob, [0xde, 0xad, 0xbe, 0xef])?;
+    /// assert_eq!(blob.read_bytes()?, [0xde, 0xad, 0xbe, 0xef]);
+    /// # std::fs::remove_file(&blob).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_bytes(&self) -> Result<Vec<u8>, AppPathError> {
+        std::fs::read(&self.full_path).map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Writes `contents` to the file, creating parent directories first.
+    ///
+    /// Replaces any existing content. For configuration or state files that must never
+    /// be left half-written, use [`Self::write_atomic()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the parent directories
+    /// cannot be created or the file cannot be written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("write_example/settings/config.toml");
+    /// config.write("port = 8080")?;
+    /// assert_eq!(config.read_to_string()?, "port = 8080");
+    /// # std::fs::remove_dir_all(AppPath::with("write_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write(&self, contents: impl AsRef<[u8]>) -> Result<(), AppPathError> {
+        self.create_parents()?;
+        std::fs::write(&self.full_path, contents)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Writes `contents` to the file atomically, creating parent directories first.
+    ///
+    /// The data is written and synced to a [`Self::temp_sibling()`] first, then renamed
+    /// over this path. Since the temporary file lives in the same directory, and thus on
+    /// the same filesystem, the rename is atomic: after a crash the file holds either the
+    /// old or the new contents, never a mix. The temporary file is removed if any step
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the failing path) if the parent
+    /// directories cannot be created, the temporary file cannot be written, or the
+    /// rename fails (e.g. because this path is a directory).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let state = AppPath::with("write_atomic_example/state.json");
+    /// state.write_atomic(r#"{"launches": 1}"#)?;
+    /// state.write_atomic(r#"{"launches": 2}"#)?;
+    /// assert_eq!(state.read_to_string()?, r#"{"launches": 2}"#);
+    /// # std::fs::remove_dir_all(AppPath::with("write_atomic_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_atomic(&self, contents: impl AsRef<[u8]>) -> Result<(), AppPathError> {
+        self.create_parents()?;
+        let temp = self.temp_sibling();
+        let result = write_synced(&temp.full_path, contents.as_ref())
+            .map_err(|e| AppPathError::from((e, &temp.full_path)))
+            .and_then(|()| {
+                std::fs::rename(&temp.full_path, &self.full_path)
+                    .map_err(|e| AppPathError::from((e, &self.full_path)))
+            });
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp.full_path);
+        }
+        result
+    }
+
+    /// Ensures the file exists and refreshes its modification time, like Unix `touch`.
+    ///
+    /// Parent directories are created first. A missing file is created empty; an
+    /// existing file keeps its content and gets its modification time set to now, which
+    /// is handy for lock, sentinel or "last seen" marker files.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the parent directories
+    /// cannot be created or the file cannot be opened or updated, e.g. because the path
+    /// is a directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let sentinel = AppPath::with("touch_example/state/.initialized");
+    /// sentinel.touch()?;
+    /// assert!(sentinel.is_file());
+    /// # std::fs::remove_dir_all(AppPath::with("touch_example")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn touch(&self) -> Result<(), AppPathError> {
+        self.create_parents()?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        file.set_modified(SystemTime::now())
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Opens the file for appending and returns a buffered writer.
+    ///
+    /// Parent directories are created first and the file is created if missing. Existing
+    /// content is never truncated, so this is a zero-ceremony way to do simple logging
+    /// with `write!`/`writeln!` without a logging crate.
+    ///
+    /// The writer is buffered; call [`Write::flush()`] to surface write errors; dropping
+    /// the writer flushes too, but ignores errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the parent directories
+    /// cannot be created or the file cannot be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::io::Write;
+    ///
+    /// let log = AppPath::with("logs/append_writer_example.log");
+    /// let mut writer = log.append_writer()?;
+    /// let port = 8080;
+    /// writeln!(writer, "service started on port {port}")?;
+    /// writer.flush()?;
+    /// # std::fs::remove_dir_all(AppPath::with("logs")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn append_writer(&self) -> Result<impl Write, AppPathError> {
+        self.create_parents()?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        Ok(BufWriter::new(file))
+    }
+
+    /// Opens the file with the given options.
+    ///
+    /// Exactly [`OpenOptions::open()`], with the path in the error. Use this for the flags
+    /// the convenience methods don't cover; no directories are created, so opening a file
+    /// below a missing directory fails. Use [`Self::open_creating()`] when `options`
+    /// create the file and missing parent directories should be created too.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the file cannot be opened,
+    /// e.g. because it or its parent directory does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::fs::OpenOptions;
+    ///
+    /// let missing = AppPath::with("open_example_missing/settings.toml");
+    /// let result = missing.open(OpenOptions::new().read(true));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn open(&self, options: &OpenOptions) -> Result<File, AppPathError> {
+        options
+            .open(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+
+    /// Creates missing parent directories, then opens the file with the given options.
+    ///
+    /// Meant for options that create the file, i.e. with
+    /// [`create`](OpenOptions::create) or [`create_new`](OpenOptions::create_new) set,
+    /// such as append-only logs or `create_new` lock files: the parents are created with
+    /// [`Self::create_parents()`] before the open, and are left in place if the open then
+    /// fails. [`OpenOptions`] cannot be inspected, so the caller picks this method over
+    /// [`Self::open()`] when creation is intended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the parent directories
+    /// cannot be created or the file cannot be opened.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::fs::OpenOptions;
+    /// use std::io::Write;
+    ///
+    /// let log = AppPath::with("open_creating_example/logs/app.log");
+    /// let mut file = log.open_creating(OpenOptions::new().create(true).append(true))?;
+    /// writeln!(file, "started")?;
+    /// # std::fs::remove_dir_all(AppPath::with("open_creating_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn open_creating(&self, options: &OpenOptions) -> Result<File, AppPathError> {
+        self.create_parents()?;
+        self.open(options)
+    }
+
+    /// Creates a new, empty temporary file in this path's parent directory.
+    ///
+    /// Picks a name with [`Self::temp_in_parent()`], creates missing parent directories
+    /// and creates the file with `create_new`, so an existing file is never reused. The
+    /// temp file shares this path's filesystem, ready to be renamed over it once written.
+    /// Removing it when no longer needed is up to the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::InvalidPath`] if this path has no parent directory, or
+    /// [`AppPathError::IoError`] (including the path) if the directories or the file cannot
+    /// be created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let database = AppPath::with("create_temp_example/users.db");
+    /// let scratch = database.create_temp_in_parent()?;
+    /// scratch.write("new contents")?;
+    /// std::fs::rename(&scratch, &database)?;
+    /// # std::fs::remove_dir_all(AppPath::with("create_temp_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn create_temp_in_parent(&self) -> Result<AppPath, AppPathError> {
+        let temp = self.temp_in_parent()?;
+        temp.open_creating(OpenOptions::new().write(true).create_new(true))?;
+        Ok(temp)
+    }
+
+    /// Returns the first existing variant of this path among the given extensions.
+    ///
+    /// Treats this path as a base name and checks `<file name>.<ext>` for each extension
+    /// in order, returning the first one that exists. The extension is appended rather
+    /// than substituted, so a dotted base name such as `app.settings` yields
+    /// `app.settings.toml`. Extensions may be given with or without a leading dot.
+    /// Supports format-agnostic config discovery.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("settings")
+    ///     .exists_with_any_extension(&["toml", "json", "yaml"]);
+    ///
+    /// match config {
+    ///     Some(path) => println!("Loading {path}"),
+    ///     None => println!("Using default settings"),
+    /// }
+    /// ```
+    pub fn exists_with_any_extension(&self, exts: &[&str]) -> Option<AppPath> {
+        let file_name = self.file_name().unwrap_or_default();
+        exts.iter()
+            .map(|ext| {
+                let mut candidate_name = file_name.to_os_string();
+                candidate_name.push(".");
+                candidate_name.push(ext.trim_start_matches('.'));
+                AppPath::from(self.full_path.with_file_name(candidate_name))
+            })
+            .find(|candidate| candidate.exists())
+    }
+
+    /// Atomically increments a `u64` counter stored in this file and returns the new value.
+    ///
+    /// The read-increment-write cycle is guarded by an advisory lock file
+    /// (`<file name>.lock` beside the counter), so concurrent threads and processes never
+    /// lose updates. A lock file older than 5 minutes was left behind by a process that
+    /// died while holding it, and is removed so the counter does not stay locked. The new
+    /// value is written to a temporary sibling and renamed into place, so readers never
+    /// observe a partially written counter. A missing counter file starts at 0, so the
+    /// first call returns 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) when:
+    /// - The lock cannot be acquired within 10 seconds ([`std::io::ErrorKind::TimedOut`])
+    /// - The file does not contain a valid `u64` ([`std::io::ErrorKind::InvalidData`])
+    /// - Reading, writing or renaming fails
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let counter = AppPath::with("increment_counter_example.seq");
+    /// let first = counter.increment_counter()?;
+    /// let second = counter.increment_counter()?;
+    /// assert_eq!(second, first + 1);
+    /// # std::fs::remove_file(&counter).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn increment_counter(&self) -> Result<u64, AppPathError> {
+        self.create_parents()?;
+        let mut lock_name = self.file_name().unwrap_or_default().to_os_string();
+        lock_name.push(".lock");
+        let _lock = LockFile::acquire(self.full_path.with_file_name(lock_name))?;
+
+        let current = match std::fs::read_to_string(&self.full_path) {
+            Ok(content) if content.trim().is_empty() => 0,
+            Ok(content) => content.trim().parse::<u64>().map_err(|e| {
+                let err = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("counter file does not contain a valid u64: {e}"),
+                );
+                AppPathError::from((err, &self.full_path))
+            })?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(AppPathError::from((e, &self.full_path))),
+        };
+        let next = current.checked_add(1).ok_or_else(|| {
+            let err = io::Error::new(io::ErrorKind::InvalidData, "counter overflow");
+            AppPathError::from((err, &self.full_path))
+        })?;
+
+        let temp = self.temp_sibling();
+        std::fs::write(&temp, next.to_string())
+            .and_then(|()| std::fs::rename(&temp, &self.full_path))
+            .map_err(|e| {
+                let _ = std::fs::remove_file(&temp);
+                AppPathError::from((e, &self.full_path))
+            })?;
+
+        Ok(next)
+    }
+
+    /// Detects the first launch by creating a marker file beside the executable.
+    ///
+    /// Resolves `marker` like [`Self::try_with()`]. If the marker does not exist yet, it
+    /// is created (along with any missing parent directories) and `true` is returned;
+    /// otherwise `false` is returned. Creation uses [`OpenOptions::create_new`], so when
+    /// several instances start at once exactly one of them sees the first run.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError`] if the base directory cannot be determined, or
+    /// [`AppPathError::IoError`] (including the path) if the parents or the marker cannot
+    /// be created for any reason other than the marker already existing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// if AppPath::first_run("state/first_run_example.marker")? {
+    ///     println!("Welcome! Setting up defaults...");
+    /// }
+    /// assert!(!AppPath::first_run("state/first_run_example.marker")?);
+    /// # std::fs::remove_dir_all(AppPath::with("state")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn first_run(marker: &str) -> Result<bool, AppPathError> {
+        let marker = Self::try_with(marker)?;
+        marker.create_parents()?;
+
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&marker.full_path)
+        {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(AppPathError::from((e, &marker.full_path))),
+        }
+    }
+
+    /// Rotates this file and its numbered copies, keeping at most `keep` old copies.
+    ///
+    /// The oldest copy ([`Self::rotated`]`(keep)`) is deleted, every remaining copy moves
+    /// up one index, and this file becomes copy `1`. Afterwards this path no longer
+    /// exists, ready for a fresh file. Missing files anywhere in the chain are skipped,
+    /// and `keep == 0` simply deletes this file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if a file cannot be deleted
+    /// or renamed. Rotation stops at the first failure, leaving earlier steps applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::fs;
+    ///
+    /// let log = AppPath::with("rotate_example/app.log");
+    /// log.create_parents()?;
+    ///
+    /// fs::write(&log, "day 1")?;
+    /// log.rotate(3)?;
+    /// fs::write(&log, "day 2")?;
+    /// log.rotate(3)?;
+    ///
+    /// assert_eq!(fs::read_to_string(log.rotated(1))?, "day 2");
+    /// assert_eq!(fs::read_to_string(log.rotated(2))?, "day 1");
+    /// # fs::remove_dir_all(AppPath::with("rotate_example")).ok();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn rotate(&self, keep: usize) -> Result<(), AppPathError> {
+        let oldest = self.rotated(keep);
+        match std::fs::remove_file(&oldest.full_path) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(AppPathError::from((e, &oldest.full_path))),
+        }
+
+        for index in (0..keep).rev() {
+            let from = self.rotated(index);
+            let to = self.rotated(index + 1);
+            match std::fs::rename(&from.full_path, &to.full_path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(AppPathError::from((e, &from.full_path))),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates `path`, writes `contents` and flushes it to disk before returning.
+fn write_synced(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(contents)?;
+    file.sync_all()
+}
+
+/// Formats a byte count using the given unit base and unit names.
+pub(crate) fn format_size(bytes: u64, base: u64, units: &[&str]) -> String {
+    if bytes < base {
+        return format!("{bytes} {}", units[0]);
+    }
+
+    let base = base as f64;
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= base && unit < units.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+    // Values such as 1023.999 KiB would otherwise display as "1024.00 KiB"
+    if (value * 100.0).round() / 100.0 >= base && unit < units.len() - 1 {
+        value /= base;
+        unit += 1;
+    }
+
+    format!("{value:.2} {}", units[unit])
+}
+
+/// Advisory cross-process lock held by exclusively creating a lock file.
+///
+/// The lock file is removed when the guard is dropped. A lock file older than
+/// [`STALE_LOCK_AGE`] belongs to a holder that died, and is broken by the next waiter.
+struct LockFile {
+    path: PathBuf,
+}
+
+impl LockFile {
+    fn acquire(path: PathBuf) -> Result<Self, AppPathError> {
+        let deadline = Instant::now() + LOCK_TIMEOUT;
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if break_stale_lock(&path) {
+                        continue;
+                    }
+                    if Instant::now() >= deadline {
+                        let err = io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            "timed out waiting for lock file",
+                        );
+                        return Err(AppPathError::from((err, &path)));
+                    }
+                    std::thread::sleep(Duration::from_millis(1));
+                }
+                Err(e) => return Err(AppPathError::from((e, &path))),
+            }
+        }
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Removes the lock file at `path` if it is older than [`STALE_LOCK_AGE`].
+///
+/// Breaking is serialized through a `<lock>.break` guard file, and staleness is checked
+/// again while holding it. Without the guard, a waiter could find the lock stale, be
+/// overtaken by another waiter that breaks it and by a new holder that takes it, and
+/// then remove the new holder's lock. Only breakers remove stale locks, so a lock still
+/// stale under the guard is the abandoned one. A guard is held for a metadata query and
+/// a removal, so one older than [`STALE_LOCK_AGE`] was abandoned as well and is removed.
+fn break_stale_lock(path: &Path) -> bool {
+    if !is_stale_lock(path) {
+        return false;
+    }
+    let mut guard_path = path.as_os_str().to_os_string();
+    guard_path.push(".break");
+    let guard_path = PathBuf::from(guard_path);
+    let guard = match OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&guard_path)
+    {
+        Ok(_) => LockFile { path: guard_path },
+        Err(_) => {
+            if is_stale_lock(&guard_path) {
+                let _ = std::fs::remove_file(&guard_path);
+            }
+            return false;
+        }
+    };
+    let broken = is_stale_lock(path) && std::fs::remove_file(path).is_ok();
+    drop(guard);
+    broken
+}
+
+/// Returns `true` if the file at `path` was last modified over [`STALE_LOCK_AGE`] ago.
+fn is_stale_lock(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age >= STALE_LOCK_AGE)
+}
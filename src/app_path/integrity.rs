@@ -0,0 +1,74 @@
+use std::io;
+
+use sha2::{Digest, Sha256};
+
+use crate::{AppPath, AppPathError};
+
+impl AppPath {
+    /// Verifies the file against its `.sha256` sidecar file.
+    ///
+    /// Reads `<file name>.sha256` from the same directory, computes the SHA-256 digest of
+    /// this file and compares the two. The sidecar may contain just the hex digest or the
+    /// `sha256sum` format (`<hex>  <file name>`); surrounding whitespace and hex case are
+    /// ignored.
+    ///
+    /// Returns `Ok(true)` when the digests match and `Ok(false)` when they differ.
+    ///
+    /// Requires the `sha2` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the offending path) when:
+    /// - The file or its sidecar cannot be read (e.g. the sidecar is missing)
+    /// - The sidecar is malformed ([`std::io::ErrorKind::InvalidData`])
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let asset = AppPath::with("assets/data.bin");
+    /// # asset.create_parents()?;
+    /// # std::fs::write(&asset, b"abc")?;
+    /// # std::fs::write(
+    /// #     AppPath::with("assets/data.bin.sha256"),
+    /// #     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  data.bin\n",
+    /// # )?;
+    /// if !asset.verify_sha256_sidecar()? {
+    ///     eprintln!("{asset} has been modified");
+    /// }
+    /// # std::fs::remove_dir_all(AppPath::with("assets")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn verify_sha256_sidecar(&self) -> Result<bool, AppPathError> {
+        let mut sidecar_name = self.file_name().unwrap_or_default().to_os_string();
+        sidecar_name.push(".sha256");
+        let sidecar = self.full_path.with_file_name(sidecar_name);
+
+        let contents =
+            std::fs::read_to_string(&sidecar).map_err(|e| AppPathError::from((e, &sidecar)))?;
+        let expected = contents
+            .split_whitespace()
+            .next()
+            .filter(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| {
+                let err = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "malformed SHA-256 sidecar (expected 64 hex characters)",
+                );
+                AppPathError::from((err, &sidecar))
+            })?;
+
+        let mut file = std::fs::File::open(&self.full_path)
+            .map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher).map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        let actual: String = hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        Ok(actual.eq_ignore_ascii_case(expected))
+    }
+}
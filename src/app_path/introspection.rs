@@ -0,0 +1,111 @@
+use std::path::Path;
+
+use crate::deployment::{detect_deployment_mode, resolve_resource};
+use crate::functions::fnv1a_64;
+use crate::{AppPath, AppPathError, DeploymentMode};
+
+impl AppPath {
+    /// Infers whether the application runs portably, installed, or from a development build.
+    ///
+    /// The heuristics are **best-effort** and only inspect the application's base directory:
+    ///
+    /// - **[`DeploymentMode::Development`]**: the base lies in a cargo `target/debug` or
+    ///   `target/release` directory (including `target/<triple>/...`), or a `Cargo.toml`
+    ///   exists in the base or one of its two nearest ancestors
+    /// - **[`DeploymentMode::Installed`]**: the base lies under a system install prefix
+    ///   (`/usr`, `/opt`, `/Applications`, `Program Files`, ...), or is not writable
+    /// - **[`DeploymentMode::Portable`]**: anything else, i.e. a writable folder outside
+    ///   system locations
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::new()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, DeploymentMode};
+    ///
+    /// let data_dir = match AppPath::deployment_mode() {
+    ///     DeploymentMode::Installed => std::env::temp_dir().join("myapp"),
+    ///     _ => AppPath::with("data").into_path_buf(),
+    /// };
+    /// ```
+    pub fn deployment_mode() -> DeploymentMode {
+        detect_deployment_mode(&Self::new())
+    }
+
+    /// Resolves a bundled resource, falling back to the project tree during development.
+    ///
+    /// Resources normally ship beside the executable, but during `cargo run` they live in
+    /// the source tree rather than next to `target/debug/myapp`. This method:
+    ///
+    /// 1. Uses `<base>/<rel>` if it exists (release and installed builds)
+    /// 2. Otherwise, if a cargo project root is detectable from the base directory
+    ///    (a `target/...` layout or a nearby `Cargo.toml`), uses `<project root>/<rel>`
+    ///    if that exists
+    /// 3. Otherwise returns `<base>/<rel>`, exactly like [`Self::with()`]
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// // Finds assets/logo.png beside the exe, or in the crate root under `cargo run`
+    /// let logo = AppPath::resource("assets/logo.png");
+    /// assert!(logo.ends_with("assets/logo.png"));
+    /// ```
+    pub fn resource(rel: impl AsRef<Path>) -> Self {
+        Self {
+            full_path: resolve_resource(&Self::new(), rel.as_ref()),
+        }
+    }
+
+    /// Returns a short identifier that is stable for this installation of the executable.
+    ///
+    /// The identifier is derived by hashing the canonicalized executable path, so it does
+    /// not depend on how the path happens to be spelled (symlinks, `..`, etc.). Running the
+    /// same binary always yields the same id, while a copy in another directory yields a
+    /// different one. Useful for namespacing per-install state in shared locations.
+    ///
+    /// The result is 16 lowercase hexadecimal characters. It is an identifier, not a
+    /// security measure.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::ExecutableNotFound`] - `std::env::current_exe()` fails
+    /// - [`AppPathError::IoError`] - The executable path cannot be canonicalized
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let id = AppPath::install_id()?;
+    /// let state_file = std::env::temp_dir().join(format!("myapp-{id}.state"));
+    /// assert_eq!(id, AppPath::install_id()?);
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn install_id() -> Result<String, AppPathError> {
+        let exe = std::env::current_exe().map_err(|e| {
+            AppPathError::ExecutableNotFound(format!("std::env::current_exe() failed: {e}"))
+        })?;
+        install_id_for(&exe)
+    }
+}
+
+/// Computes the install identifier for the given executable path.
+pub(crate) fn install_id_for(exe: &Path) -> Result<String, AppPathError> {
+    let canonical = AppPath {
+        full_path: exe
+            .canonicalize()
+            .map_err(|e| AppPathError::from((e, &exe.to_path_buf())))?,
+    };
+    Ok(format!("{:016x}", fnv1a_64(&canonical.to_bytes())))
+}
@@ -0,0 +1,45 @@
+use std::fs::File;
+
+use memmap2::Mmap;
+
+use crate::{AppPath, AppPathError};
+
+impl AppPath {
+    /// Opens the file and maps it read-only into memory.
+    ///
+    /// Gives zero-copy access to large bundled assets such as databases or search
+    /// indexes. The returned [`Mmap`] dereferences to `&[u8]`.
+    ///
+    /// The mapping reflects the file on disk: if another process truncates or modifies
+    /// the file while it is mapped, reads may observe the change or even fault. Only map
+    /// files your application treats as read-only.
+    ///
+    /// Requires the `mmap` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::IoError`] (including the path) if the file cannot be
+    /// opened or mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let index = AppPath::with("assets/search.idx");
+    /// # index.create_parents()?;
+    /// # std::fs::write(&index, b"IDX1")?;
+    /// let map = index.mmap()?;
+    /// assert_eq!(&map[..4], b"IDX1");
+    /// # drop(map);
+    /// # std::fs::remove_dir_all(AppPath::with("assets")).ok();
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn mmap(&self) -> Result<Mmap, AppPathError> {
+        let file =
+            File::open(&self.full_path).map_err(|e| AppPathError::from((e, &self.full_path)))?;
+        // SAFETY: the mapping is read-only; concurrent modification of the underlying
+        // file is documented above as the caller's responsibility.
+        unsafe { Mmap::map(&file) }.map_err(|e| AppPathError::from((e, &self.full_path)))
+    }
+}
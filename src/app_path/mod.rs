@@ -2,6 +2,10 @@
 
 use std::path::PathBuf;
 
+pub use builder::AppPathBuilder;
+pub use relative::RelativeAppPath;
+pub use shared::AppPathShared;
+
 /// Creates paths relative to the executable location for portable applications.
 ///
 /// **AppPath** enables building truly portable applications where configuration, data,
@@ -28,6 +32,8 @@ use std::path::PathBuf;
 /// - [`Self::try_with_override()`] - **Deployment (Fallible)**: Fallible environment-configurable paths
 /// - [`Self::with_override_fn()`] - **Advanced**: Function-based override logic
 /// - [`Self::try_with_override_fn()`] - **Advanced (Fallible)**: Fallible function-based override logic
+/// - [`Self::with_override_envs()`] - **Deployment**: First non-empty of several environment variables
+/// - [`Self::builder()`] - **Composition**: Combine a default, ordered overrides, base choice and portability in one call
 ///
 /// ### Directory Creation
 ///
@@ -41,6 +47,7 @@ use std::path::PathBuf;
 /// - [`Self::into_inner()`] - **Conversion**: Alias for `into_path_buf()` following Rust patterns
 /// - [`Self::to_bytes()`] - **Ecosystem**: Raw bytes for specialized libraries
 /// - [`Self::into_bytes()`] - **Ecosystem**: Owned bytes for specialized libraries
+/// - [`Self::into_shared()`] - **Sharing**: Reference-counted [`AppPathShared`] with allocation-free `Clone`
 ///
 /// # Panics
 ///
@@ -86,7 +93,29 @@ pub struct AppPath {
     full_path: PathBuf,
 }
 
-mod constructors;
+#[cfg(feature = "tokio")]
+mod async_override;
+mod base;
+mod builder;
+pub(crate) mod constructors;
 mod directory;
+mod env_overrides;
+pub(crate) mod file_ops;
+#[cfg(feature = "sha2")]
+mod integrity;
+pub(crate) mod introspection;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod path_ops;
+mod policy;
+mod relative;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shared;
+#[cfg(feature = "testing")]
+mod testing;
 mod traits;
+#[cfg(feature = "typed-path")]
+mod typed;
+#[cfg(feature = "camino")]
+mod utf8;
@@ -1,6 +1,14 @@
-use std::path::Path;
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::path::{Component, Path};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::AppPath;
+use super::base::lexically_normalize;
+use crate::{AppPath, AppPathError};
+
+// Per-process counter that keeps temp names unique even within one clock tick
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 impl AppPath {
     /// Joins additional path segments to create a new AppPath.
@@ -29,6 +37,184 @@ impl AppPath {
         }
     }
 
+    /// Joins every segment of `segments` onto this path, in order.
+    ///
+    /// Equivalent to folding with [`Self::join()`] but builds a single new path. As with
+    /// [`PathBuf::push`](std::path::PathBuf::push), an absolute segment replaces
+    /// everything before it. An empty iterator returns a copy of this path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let route = vec!["users".to_string(), "42".to_string(), "avatar.png".to_string()];
+    /// let avatar = AppPath::with("static").join_all(&route);
+    /// assert_eq!(avatar, AppPath::with("static/users/42/avatar.png"));
+    /// ```
+    pub fn join_all(&self, segments: impl IntoIterator<Item = impl AsRef<Path>>) -> Self {
+        let mut full_path = self.full_path.clone();
+        full_path.extend(segments);
+        Self { full_path }
+    }
+
+    /// Joins `segment` onto this path, rejecting segments that could leave it.
+    ///
+    /// The checked counterpart of [`Self::join()`] for untrusted input: a segment with a
+    /// root or prefix (`/etc/passwd`, `C:\x`, `\\server\share`) would replace the whole
+    /// path, and a `..` component could climb above it, so both are rejected. `.`
+    /// components are harmless and allowed. The check is purely lexical and never touches
+    /// the filesystem, so symbolic links below this path are not detected. For `/`-separated
+    /// URL paths, [`Self::resolve_request()`] is stricter still.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::AbsolutePathRejected`] if `segment` has a root or prefix
+    /// - [`AppPathError::PathEscapesBase`] if `segment` contains a `..` component, with
+    ///   `base` set to this path
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// let uploads = AppPath::with("uploads");
+    /// assert_eq!(uploads.join_checked("2024/photo.jpg")?, AppPath::with("uploads/2024/photo.jpg"));
+    ///
+    /// assert!(matches!(
+    ///     uploads.join_checked("/etc/passwd"),
+    ///     Err(AppPathError::AbsolutePathRejected(_))
+    /// ));
+    /// assert!(matches!(
+    ///     uploads.join_checked("../config.toml"),
+    ///     Err(AppPathError::PathEscapesBase { .. })
+    /// ));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn join_checked(&self, segment: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let segment = segment.as_ref();
+        for component in segment.components() {
+            match component {
+                Component::Prefix(_) | Component::RootDir => {
+                    return Err(AppPathError::AbsolutePathRejected(segment.to_path_buf()));
+                }
+                Component::ParentDir => {
+                    return Err(AppPathError::PathEscapesBase {
+                        path: self.full_path.join(segment),
+                        base: self.full_path.clone(),
+                    });
+                }
+                Component::CurDir | Component::Normal(_) => {}
+            }
+        }
+        Ok(self.join(segment))
+    }
+
+    /// Extends the path with `segment` in place.
+    ///
+    /// Behaves like [`PathBuf::push`](std::path::PathBuf::push): an absolute `segment`
+    /// replaces the whole path. Use this instead of [`Self::join()`] when assembling a
+    /// deep path step by step, e.g. in a loop, to avoid a new allocation per step.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let mut path = AppPath::with("data");
+    /// for segment in ["2024", "06", "events.log"] {
+    ///     path.push(segment);
+    /// }
+    /// assert_eq!(path, AppPath::with("data/2024/06/events.log"));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, segment: impl AsRef<Path>) {
+        self.full_path.push(segment);
+    }
+
+    /// Truncates the path to its parent in place.
+    ///
+    /// Behaves like [`PathBuf::pop`](std::path::PathBuf::pop): returns `false` and leaves
+    /// the path unchanged when there is no parent, i.e. at the filesystem root. The
+    /// application's base directory is not a boundary; popping it moves above it, as
+    /// with [`Self::parent()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let mut path = AppPath::with("data/users/profile.json");
+    /// assert!(path.pop());
+    /// assert_eq!(path, AppPath::with("data/users"));
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> bool {
+        self.full_path.pop()
+    }
+
+    /// Safely joins an untrusted, `/`-separated request path onto this directory.
+    ///
+    /// This is the hardened entry point for serving static files: `assets/css/site.css`
+    /// resolves below `self`, while any attempt to leave it is rejected. A single
+    /// leading `/` is ignored, as in URL paths. Every remaining segment must be a
+    /// plain file name; the request is rejected if any segment:
+    ///
+    /// - is empty (`a//b`, trailing `/`)
+    /// - is `.` or `..`
+    /// - contains `\`, `:` or a NUL byte (Windows separators, drive prefixes and
+    ///   alternate data streams)
+    ///
+    /// Nothing is percent-decoded; decode the request path first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::PathEscapesBase`] for any rejected request, with `base`
+    /// set to this path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// let public = AppPath::with("public");
+    ///
+    /// let css = public.resolve_request("/assets/css/site.css")?;
+    /// assert_eq!(css, AppPath::with("public/assets/css/site.css"));
+    ///
+    /// assert!(matches!(
+    ///     public.resolve_request("assets/css/../../etc/passwd"),
+    ///     Err(AppPathError::PathEscapesBase { .. })
+    /// ));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn resolve_request(&self, request_path: &str) -> Result<AppPath, AppPathError> {
+        let rejected = || AppPathError::PathEscapesBase {
+            path: self.full_path.join(request_path),
+            base: self.full_path.clone(),
+        };
+
+        let request = request_path.strip_prefix('/').unwrap_or(request_path);
+        let mut full_path = self.full_path.clone();
+        for segment in request.split('/') {
+            let plain_name = !segment.is_empty()
+                && segment != "."
+                && segment != ".."
+                && !segment.contains(['\\', ':', '\0']);
+            // Belt and braces: the platform must also see exactly one normal component
+            let mut components = Path::new(segment).components();
+            let single_normal = matches!(
+                (components.next(), components.next()),
+                (Some(Component::Normal(_)), None)
+            );
+            if !plain_name || !single_normal {
+                return Err(rejected());
+            }
+            full_path.push(segment);
+        }
+        Ok(AppPath { full_path })
+    }
+
     /// Returns the parent directory as an AppPath, if it exists.
     ///
     /// Returns `None` if this path is a root directory or has no parent.
@@ -46,7 +232,116 @@ impl AppPath {
     /// ```
     #[inline]
     pub fn parent(&self) -> Option<Self> {
-        self.full_path.parent().map(Self::with)
+        self.full_path.parent().map(|parent| Self {
+            full_path: parent.to_path_buf(),
+        })
+    }
+
+    /// Returns an iterator over this path and its ancestors, each as an `AppPath`.
+    ///
+    /// Yields the same sequence as [`Path::ancestors`], starting with the path itself
+    /// and ending at the filesystem root, but re-wrapped so upward searches keep
+    /// working with `AppPath` methods. Like [`Self::parent()`], the application's base
+    /// directory is not a boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// // Find the nearest directory containing a marker file
+    /// let start = AppPath::with("plugins/audio/lib");
+    /// let project_root = start
+    ///     .ancestor_app_paths()
+    ///     .find(|dir| dir.join(".project-root").exists());
+    /// # let _ = project_root;
+    /// ```
+    pub fn ancestor_app_paths(&self) -> impl Iterator<Item = AppPath> + '_ {
+        self.full_path.ancestors().map(|ancestor| AppPath {
+            full_path: ancestor.to_path_buf(),
+        })
+    }
+
+    /// Creates a new AppPath with the file name replaced.
+    ///
+    /// Mirrors [`Path::with_file_name`](std::path::Path::with_file_name), including its
+    /// edge cases: if the path has no file name (e.g. it ends in `..`), `name` is
+    /// appended instead, and an empty `name` simply removes the file name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config/app.toml");
+    /// let backup = config.with_file_name("backup.toml");
+    /// assert_eq!(backup, AppPath::with("config/backup.toml"));
+    /// ```
+    #[inline]
+    pub fn with_file_name(&self, name: impl AsRef<Path>) -> Self {
+        Self {
+            full_path: self.full_path.with_file_name(name.as_ref()),
+        }
+    }
+
+    /// Returns the path `name` next to this one, in the same parent directory.
+    ///
+    /// Like [`Self::with_file_name()`], but `name` may have several components
+    /// (`archive/log.1`), which are joined onto the parent directory. If this path has
+    /// no parent (a filesystem root or the empty path), `name` is resolved against the
+    /// application's base directory as in [`Self::with()`]. An absolute `name` replaces
+    /// the path entirely, as with [`Self::join()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no parent and the application's base directory cannot be
+    /// determined, as [`Self::with()`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let log = AppPath::with("logs/app.log");
+    /// assert_eq!(log.sibling("app.log.1"), AppPath::with("logs/app.log.1"));
+    /// assert_eq!(log.sibling("archive/app.log.1"), AppPath::with("logs/archive/app.log.1"));
+    /// ```
+    pub fn sibling(&self, name: impl AsRef<Path>) -> Self {
+        match self.full_path.parent() {
+            Some(parent) => Self {
+                full_path: parent.join(name),
+            },
+            None => Self::with(name),
+        }
+    }
+
+    /// Returns the file to match and the directory to watch for file-watcher crates.
+    ///
+    /// Watchers such as `notify` are most reliable on a directory: editors often save by
+    /// writing a new file and renaming it over the old one, which a watch on the file
+    /// itself can miss. The pair is `(self, parent)`; register the second with the
+    /// watcher and filter its events on the first. If this path has no parent, the
+    /// application's base directory is watched instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no parent and the application's base directory cannot be
+    /// determined, as [`Self::new()`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config/app.toml");
+    /// let (file, dir) = config.watch_targets();
+    ///
+    /// assert_eq!(file, config);
+    /// assert_eq!(dir, AppPath::with("config"));
+    /// ```
+    pub fn watch_targets(&self) -> (Self, Self) {
+        let dir = self.parent().unwrap_or_default();
+        (self.clone(), dir)
     }
 
     /// Creates a new AppPath with the specified file extension.
@@ -68,7 +363,563 @@ impl AppPath {
     /// ```
     #[inline]
     pub fn with_extension(&self, ext: &str) -> Self {
-        Self::with(self.full_path.with_extension(ext))
+        Self {
+            full_path: self.full_path.with_extension(ext),
+        }
+    }
+
+    /// Returns `true` if the file name ends with the extension `ext`, ignoring ASCII case.
+    ///
+    /// A leading dot is optional, so `"json"` and `".json"` are equivalent. `ext` may
+    /// span several dots (`"tar.gz"`), in which case the trailing suffix of the file
+    /// name is matched. As with [`Path::extension`], a hidden file such as `.json` has no
+    /// extension, and an empty `ext` never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// assert!(AppPath::with("data/users.JSON").has_extension("json"));
+    /// assert!(AppPath::with("backup.tar.gz").has_extension(".tar.gz"));
+    /// assert!(!AppPath::with("README").has_extension("md"));
+    /// ```
+    pub fn has_extension(&self, ext: &str) -> bool {
+        let ext = ext.strip_prefix('.').unwrap_or(ext).as_bytes();
+        let Some(name) = self.full_path.file_name() else {
+            return false;
+        };
+        let name = name.to_string_lossy();
+        let name = name.as_bytes();
+
+        // The name needs a non-empty stem, a dot, then the extension
+        !ext.is_empty()
+            && name.len() > ext.len() + 1
+            && name[name.len() - ext.len() - 1] == b'.'
+            && name[name.len() - ext.len()..].eq_ignore_ascii_case(ext)
+    }
+
+    /// Returns `true` if the file name ends with any of `exts`, ignoring ASCII case.
+    ///
+    /// Each entry is matched as in [`Self::has_extension()`], so multi-part extensions
+    /// such as `"tar.gz"` work alongside single ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let archive = AppPath::with("downloads/release.TAR.GZ");
+    /// assert!(archive.has_any_extension(&["tar.gz", "tgz"]));
+    /// assert!(!archive.has_any_extension(&["zip", "7z"]));
+    /// ```
+    pub fn has_any_extension(&self, exts: &[&str]) -> bool {
+        exts.iter().any(|ext| self.has_extension(ext))
+    }
+
+    /// Returns the path of the `index`-th rotated copy of this file.
+    ///
+    /// Inserts `.{index}` before the extension: `app.log` becomes `app.1.log`,
+    /// `app.2.log`, and so on. Files without an extension get the index appended
+    /// (`app` becomes `app.1`). Index `0` returns this path unchanged. See
+    /// [`Self::rotate()`] for shifting files through these names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let log = AppPath::with("logs/app.log");
+    /// assert_eq!(log.rotated(0), log);
+    /// assert_eq!(log.rotated(1), AppPath::with("logs/app.1.log"));
+    /// assert_eq!(log.rotated(2), AppPath::with("logs/app.2.log"));
+    /// ```
+    pub fn rotated(&self, index: usize) -> Self {
+        if index == 0 {
+            return self.clone();
+        }
+
+        let mut name = self
+            .full_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_os_string();
+        name.push(format!(".{index}"));
+        if let Some(ext) = self.full_path.extension() {
+            name.push(".");
+            name.push(ext);
+        }
+        Self {
+            full_path: self.full_path.with_file_name(name),
+        }
+    }
+
+    /// Returns a uniquely named temporary path in the same directory as this path.
+    ///
+    /// Because the temporary path shares this path's parent directory, it lives on the same
+    /// filesystem, which is what makes the write-then-rename trick atomic. Names combine the
+    /// original file name, the process id, a per-process counter and a timestamp, so repeated
+    /// calls never return the same path. Nothing is created on disk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config.toml");
+    /// let temp = config.temp_sibling();
+    ///
+    /// assert_eq!(temp.parent(), config.parent());
+    /// assert_ne!(temp, config.temp_sibling());
+    /// ```
+    pub fn temp_sibling(&self) -> Self {
+        let stem = self
+            .full_path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_else(|| "tmp".into());
+        let counter = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.subsec_nanos())
+            .unwrap_or_default();
+        let pid = std::process::id();
+
+        Self {
+            full_path: self
+                .full_path
+                .with_file_name(format!(".{stem}.{pid}.{counter}.{nanos}.tmp")),
+        }
+    }
+
+    /// Returns a uniquely named temporary path in this path's parent directory.
+    ///
+    /// The checked form of [`Self::temp_sibling()`]: same naming scheme and guarantees,
+    /// but fails instead of producing a path that would not share a directory with this
+    /// one. Nothing is created on disk; see [`Self::create_temp_in_parent()`] for that.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::InvalidPath`] if this path has no parent directory, i.e. it
+    /// is a filesystem root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let database = AppPath::with("data/users.db");
+    /// let temp = database.temp_in_parent()?;
+    ///
+    /// assert_eq!(temp.parent(), database.parent());
+    /// assert_ne!(temp, database.temp_in_parent()?);
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn temp_in_parent(&self) -> Result<Self, AppPathError> {
+        if self.full_path.parent().is_none() {
+            return Err(AppPathError::InvalidPath {
+                path: self.full_path.clone(),
+                reason: "path has no parent directory".to_string(),
+            });
+        }
+        Ok(self.temp_sibling())
+    }
+
+    /// Rewrites the path prefix into one consistent form (Windows only).
+    ///
+    /// Windows accepts several spellings of the same location, and [`PartialEq`] or
+    /// [`Path::strip_prefix`](std::path::Path::strip_prefix) treat them as different.
+    /// This method picks the plain Win32 form:
+    ///
+    /// | Input prefix | Normalized to |
+    /// |--------------|---------------|
+    /// | `\\?\C:\`, `c:\` | `C:\` (verbatim marker dropped, drive letter uppercased) |
+    /// | `\\?\UNC\server\share\` | `\\server\share\` |
+    /// | `\\localhost\c$\`, `\\127.0.0.1\c$\` | `C:\` (local administrative share) |
+    /// | `\\server\share\` | unchanged |
+    /// | `\\.\device`, other `\\?\` forms | unchanged |
+    ///
+    /// Only the prefix is rewritten; the rest of the path is kept as-is and the
+    /// filesystem is never touched. A UNC path to a remote share cannot be mapped to a
+    /// drive letter without querying the system, so it stays in UNC form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(windows)]
+    /// # {
+    /// use app_path::AppPath;
+    ///
+    /// let verbatim = AppPath::with(r"\\?\C:\app\config.toml").normalize_prefix();
+    /// let plain = AppPath::with(r"c:\app\config.toml").normalize_prefix();
+    /// assert_eq!(verbatim, plain);
+    /// # }
+    /// ```
+    #[cfg(windows)]
+    pub fn normalize_prefix(&self) -> Self {
+        use std::ffi::{OsStr, OsString};
+        use std::path::{Component, PathBuf, Prefix};
+
+        fn disk(letter: u8) -> OsString {
+            OsString::from(format!("{}:", char::from(letter.to_ascii_uppercase())))
+        }
+
+        fn unc(server: &OsStr, share: &OsStr) -> OsString {
+            // `\\localhost\c$` is the administrative share for the local C: drive
+            let is_local = server
+                .to_str()
+                .is_some_and(|s| s.eq_ignore_ascii_case("localhost") || s == "127.0.0.1");
+            if let Some([letter, b'$']) = share.to_str().map(str::as_bytes) {
+                if is_local && letter.is_ascii_alphabetic() {
+                    return disk(*letter);
+                }
+            }
+            let mut prefix = OsString::from(r"\\");
+            prefix.push(server);
+            prefix.push(r"\");
+            prefix.push(share);
+            prefix
+        }
+
+        let mut components = self.full_path.components();
+        let prefix = match components.next() {
+            Some(Component::Prefix(prefix)) => match prefix.kind() {
+                Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => disk(letter),
+                Prefix::UNC(server, share) | Prefix::VerbatimUNC(server, share) => {
+                    unc(server, share)
+                }
+                Prefix::Verbatim(_) | Prefix::DeviceNS(_) => return self.clone(),
+            },
+            _ => return self.clone(),
+        };
+
+        let mut full_path = PathBuf::from(prefix);
+        for component in components {
+            full_path.push(component);
+        }
+        Self { full_path }
+    }
+
+    /// Returns `true` if the path is a UNC path (Windows only).
+    ///
+    /// Both the plain `\\server\share\...` form and the verbatim
+    /// `\\?\UNC\server\share\...` form count.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(windows)]
+    /// # {
+    /// use app_path::AppPath;
+    ///
+    /// assert!(AppPath::with(r"\\server\share\config.toml").is_unc());
+    /// assert!(!AppPath::with(r"C:\app\config.toml").is_unc());
+    /// # }
+    /// ```
+    #[cfg(windows)]
+    pub fn is_unc(&self) -> bool {
+        use std::path::Prefix;
+
+        matches!(
+            self.prefix_kind(),
+            Some(Prefix::UNC(..) | Prefix::VerbatimUNC(..))
+        )
+    }
+
+    /// Returns `true` if the path starts with the verbatim `\\?\` prefix (Windows only).
+    ///
+    /// Verbatim paths are what [`std::fs::canonicalize`] returns on Windows; many
+    /// programs and dialogs do not accept them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(windows)]
+    /// # {
+    /// use app_path::AppPath;
+    ///
+    /// assert!(AppPath::with(r"\\?\C:\app\config.toml").is_verbatim());
+    /// assert!(!AppPath::with(r"C:\app\config.toml").is_verbatim());
+    /// # }
+    /// ```
+    #[cfg(windows)]
+    pub fn is_verbatim(&self) -> bool {
+        self.prefix_kind()
+            .is_some_and(|prefix| prefix.is_verbatim())
+    }
+
+    /// Removes the verbatim `\\?\` prefix, if present (Windows only).
+    ///
+    /// `\\?\C:\...` becomes `C:\...` and `\\?\UNC\server\share\...` becomes
+    /// `\\server\share\...`; everything else, including other `\\?\` forms that have no
+    /// plain equivalent, is returned unchanged. Unlike [`Self::normalize_prefix()`], the
+    /// drive letter's case and local administrative shares are left alone.
+    ///
+    /// The plain form is subject to the usual Win32 path rules again, so paths longer
+    /// than `MAX_PATH` may stop working on systems without long path support.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(windows)]
+    /// # {
+    /// use app_path::AppPath;
+    ///
+    /// let canonical = AppPath::with(r"\\?\C:\app\config.toml");
+    /// assert_eq!(canonical.strip_verbatim(), AppPath::with(r"C:\app\config.toml"));
+    /// # }
+    /// ```
+    #[cfg(windows)]
+    pub fn strip_verbatim(&self) -> Self {
+        use std::ffi::OsString;
+        use std::path::{Component, PathBuf, Prefix};
+
+        let mut components = self.full_path.components();
+        let prefix = match components.next() {
+            Some(Component::Prefix(prefix)) => match prefix.kind() {
+                Prefix::VerbatimDisk(letter) => OsString::from(format!("{}:", char::from(letter))),
+                Prefix::VerbatimUNC(server, share) => {
+                    let mut prefix = OsString::from(r"\\");
+                    prefix.push(server);
+                    prefix.push(r"\");
+                    prefix.push(share);
+                    prefix
+                }
+                _ => return self.clone(),
+            },
+            _ => return self.clone(),
+        };
+
+        let mut full_path = PathBuf::from(prefix);
+        for component in components {
+            full_path.push(component);
+        }
+        Self { full_path }
+    }
+
+    /// Returns the kind of the path's Windows prefix, if it has one.
+    #[cfg(windows)]
+    fn prefix_kind(&self) -> Option<std::path::Prefix<'_>> {
+        match self.full_path.components().next() {
+            Some(std::path::Component::Prefix(prefix)) => Some(prefix.kind()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if both paths name the same file as the filesystem would see it.
+    ///
+    /// Unlike the strict [`PartialEq`], this comparison:
+    ///
+    /// - ignores trailing and repeated separators (`data/` equals `data`)
+    /// - ignores case on platforms whose default filesystems are case-insensitive
+    ///   (Windows and macOS)
+    /// - treats `/` and `\` alike on Windows
+    ///
+    /// The comparison is purely lexical: the filesystem is never touched, so symbolic
+    /// links, `..` components and case-sensitive volumes on macOS are not taken into
+    /// account. Use [`std::fs::canonicalize`] when that matters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let a = AppPath::with("data/cache/");
+    /// let b = AppPath::with("data//cache");
+    /// assert!(a.fs_eq(&b));
+    ///
+    /// let upper = AppPath::with("Data/Cache");
+    /// assert_eq!(a.fs_eq(&upper), cfg!(any(windows, target_os = "macos")));
+    /// ```
+    pub fn fs_eq(&self, other: &AppPath) -> bool {
+        const CASE_INSENSITIVE: bool = cfg!(any(windows, target_os = "macos"));
+
+        let mut left = self.full_path.components();
+        let mut right = other.full_path.components();
+        loop {
+            match (left.next(), right.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) => {
+                    let (a, b) = (a.as_os_str(), b.as_os_str());
+                    let same = if CASE_INSENSITIVE {
+                        a.to_string_lossy().to_lowercase() == b.to_string_lossy().to_lowercase()
+                    } else {
+                        a == b
+                    };
+                    if !same {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns `true` if both paths are equal ignoring ASCII case.
+    ///
+    /// Compares component by component like [`PartialEq`], but `Config.TOML` equals
+    /// `config.toml`. Only ASCII letters are folded; this is not full Unicode case
+    /// folding, so `Ä` and `ä` still differ. Components that are not valid UTF-8 are
+    /// compared exactly. Unlike [`Self::fs_eq()`], case is ignored on every platform,
+    /// and separators are not otherwise normalized. The default `Eq` and `Ord` stay exact.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("Config.TOML");
+    /// assert!(config.eq_ignore_case(&AppPath::with("config.toml")));
+    /// assert!(!config.eq_ignore_case(&AppPath::with("settings.toml")));
+    /// ```
+    pub fn eq_ignore_case(&self, other: &AppPath) -> bool {
+        let mut left = self.full_path.components();
+        let mut right = other.full_path.components();
+        loop {
+            match (left.next(), right.next()) {
+                (None, None) => return true,
+                (Some(a), Some(b)) => {
+                    let (a, b) = (a.as_os_str(), b.as_os_str());
+                    let same = match (a.to_str(), b.to_str()) {
+                        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+                        _ => a == b,
+                    };
+                    if !same {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns `true` if both paths are equal after lexical normalization.
+    ///
+    /// `.` components are removed and `..` components applied before comparing, so
+    /// `config.toml`, `./config.toml` and `foo/../config.toml` are all equal. The strict
+    /// [`PartialEq`] is unchanged; use this for deduplication where equivalent spellings
+    /// should collapse. The filesystem is never touched, so symbolic links are not
+    /// resolved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let plain = AppPath::with("config.toml");
+    /// assert!(plain.eq_normalized(&AppPath::with("./config.toml")));
+    /// assert!(plain.eq_normalized(&AppPath::with("foo/../config.toml")));
+    /// ```
+    pub fn eq_normalized(&self, other: &AppPath) -> bool {
+        lexically_normalize(&self.full_path) == lexically_normalize(&other.full_path)
+    }
+
+    /// Feeds the lexically normalized path into `state`.
+    ///
+    /// Consistent with [`Self::eq_normalized()`]: paths that are `eq_normalized` hash
+    /// identically. Use it to build a normalizing key type for sets and maps.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::collections::HashSet;
+    /// use std::hash::{Hash, Hasher};
+    ///
+    /// struct NormalizedKey(AppPath);
+    ///
+    /// impl PartialEq for NormalizedKey {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0.eq_normalized(&other.0)
+    ///     }
+    /// }
+    /// impl Eq for NormalizedKey {}
+    /// impl Hash for NormalizedKey {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.0.hash_normalized(state);
+    ///     }
+    /// }
+    ///
+    /// let keys: HashSet<_> = ["config.toml", "./config.toml", "data/../config.toml"]
+    ///     .into_iter()
+    ///     .map(|path| NormalizedKey(AppPath::with(path)))
+    ///     .collect();
+    /// assert_eq!(keys.len(), 1);
+    /// ```
+    pub fn hash_normalized<H: Hasher>(&self, state: &mut H) {
+        lexically_normalize(&self.full_path).hash(state);
+    }
+
+    /// Renders the path using only the platform's native separator.
+    ///
+    /// On Windows, paths built from mixed input (`C:\app/config\file.toml`) keep both
+    /// separators; this renders every `/` as `\` for tidy logs. On other platforms `\`
+    /// is an ordinary file name character, so the path is rendered as-is.
+    ///
+    /// This is for display only: the stored path, equality and hashing are unaffected.
+    /// Non-UTF-8 sequences are replaced as in [`Path::to_string_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config/app.toml");
+    /// println!("Loading {}", config.display_normalized());
+    /// ```
+    pub fn display_normalized(&self) -> String {
+        let rendered = self.full_path.to_string_lossy();
+        if cfg!(windows) {
+            rendered.replace('/', "\\")
+        } else {
+            rendered.into_owned()
+        }
+    }
+
+    /// Renders the path with `/` separators on every platform.
+    ///
+    /// On Windows every `\` is rendered as `/`, so `C:\app\config.toml` becomes
+    /// `C:/app/config.toml`; on other platforms the path is rendered as-is, since `\` is
+    /// an ordinary file name character there. Use this for logs, manifests and golden
+    /// files that must read the same on every OS; unlike [`Display`](std::fmt::Display)
+    /// it never uses native separators.
+    ///
+    /// This is for display and serialization only, not for reopening files: the result
+    /// may not round-trip to the same path (e.g. verbatim `\\?\` paths on Windows).
+    /// Non-UTF-8 sequences are replaced as in [`Path::to_string_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config/app.toml");
+    /// assert!(config.display_portable().ends_with("config/app.toml"));
+    /// ```
+    pub fn display_portable(&self) -> String {
+        let rendered = self.full_path.to_string_lossy();
+        if cfg!(windows) {
+            rendered.replace('\\', "/")
+        } else {
+            rendered.into_owned()
+        }
+    }
+
+    /// Returns `true` if `name` looks like a file created by [`Self::temp_sibling()`].
+    pub(crate) fn is_temp_sibling_name(name: &str) -> bool {
+        let Some(inner) = name
+            .strip_prefix('.')
+            .and_then(|rest| rest.strip_suffix(".tmp"))
+        else {
+            return false;
+        };
+        // `{stem}.{pid}.{counter}.{nanos}`: the last three fields are numeric
+        let mut fields = inner.rsplitn(4, '.');
+        (0..3).all(|_| {
+            fields
+                .next()
+                .is_some_and(|field| !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit()))
+        }) && fields.next().is_some()
     }
 
     /// Consumes the `AppPath` and returns the internal `PathBuf`.
@@ -116,6 +967,44 @@ impl AppPath {
         self.into_path_buf()
     }
 
+    /// Borrows the path as a [`Cow::Borrowed`], without allocating.
+    ///
+    /// For APIs taking `Into<Cow<'_, Path>>` that sometimes keep the path and sometimes
+    /// only inspect it; pair with [`Self::into_cow()`] when handing over ownership.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::borrow::Cow;
+    ///
+    /// let config = AppPath::with("config.toml");
+    /// assert!(matches!(config.as_cow(), Cow::Borrowed(_)));
+    /// ```
+    #[inline]
+    pub fn as_cow(&self) -> Cow<'_, Path> {
+        Cow::Borrowed(&self.full_path)
+    }
+
+    /// Consumes the `AppPath` and returns the path as a [`Cow::Owned`].
+    ///
+    /// Moves the internal `PathBuf` like [`Self::into_path_buf()`], so no copy is made.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::borrow::Cow;
+    ///
+    /// let config = AppPath::with("config.toml");
+    /// let owned: Cow<'static, std::path::Path> = config.into_cow();
+    /// assert!(matches!(owned, Cow::Owned(_)));
+    /// ```
+    #[inline]
+    pub fn into_cow(self) -> Cow<'static, Path> {
+        Cow::Owned(self.full_path)
+    }
+
     /// Returns the path as encoded bytes for low-level path operations.
     ///
     /// This provides access to the platform-specific byte representation of the path.
@@ -207,4 +1096,69 @@ impl AppPath {
             self.to_string_lossy().into_owned().into_bytes()
         }
     }
+
+    /// Returns a stable, portable byte form of the path for storage, e.g. in a database.
+    ///
+    /// Unlike [`Self::to_bytes()`], the result is plain UTF-8 text with `/` separators,
+    /// identical on every platform. Paths inside the application's base directory are
+    /// stored relative to it (`data/users.db`, or an empty string for the base itself),
+    /// so they reload correctly on another machine or OS with a different install
+    /// location. Paths outside the base are stored in absolute form, which only
+    /// round-trips on the platform family that produced it. Components that are not
+    /// valid Unicode are replaced with `U+FFFD`, as in [`Path::to_string_lossy`].
+    ///
+    /// Use [`Self::from_bytes_portable()`] to reconstruct the path.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let db = AppPath::with("data/users.db");
+    /// let stored = db.bytes_portable();
+    /// assert_eq!(stored, b"data/users.db");
+    /// assert_eq!(AppPath::from_bytes_portable(&stored)?, db);
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn bytes_portable(&self) -> Vec<u8> {
+        let portable = match self.strip_base() {
+            Some(relative) if self.is_within_base() => relative.to_string(),
+            _ => self.display_portable(),
+        };
+        portable.into_bytes()
+    }
+
+    /// Reconstructs a path from the output of [`Self::bytes_portable()`].
+    ///
+    /// Relative forms resolve against the current application base directory, as with
+    /// [`Self::try_with()`]; absolute forms are used as-is, subject to the same absolute
+    /// path policy.
+    ///
+    /// # Errors
+    ///
+    /// - [`AppPathError::InvalidPath`] if `bytes` is not valid UTF-8 or contains a NUL byte
+    /// - Any error from [`Self::try_with()`], e.g. when the base directory cannot be
+    ///   determined
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathError};
+    ///
+    /// let avatar = AppPath::from_bytes_portable("users/zoë/avatar.png".as_bytes())?;
+    /// assert_eq!(avatar, AppPath::with("users/zoë/avatar.png"));
+    ///
+    /// assert!(matches!(
+    ///     AppPath::from_bytes_portable(&[0xff, 0xfe]),
+    ///     Err(AppPathError::InvalidPath { .. })
+    /// ));
+    /// # Ok::<(), AppPathError>(())
+    /// ```
+    pub fn from_bytes_portable(bytes: &[u8]) -> Result<Self, AppPathError> {
+        let text = std::str::from_utf8(bytes).map_err(|e| AppPathError::InvalidPath {
+            path: String::from_utf8_lossy(bytes).into_owned().into(),
+            reason: format!("portable path is not valid UTF-8: {e}"),
+        })?;
+        Self::try_with(text)
+    }
 }
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use crate::{policy, AbsolutePolicy, AppPath};
+
+impl AppPath {
+    /// Sets the process-wide policy for absolute path inputs.
+    ///
+    /// The policy is consulted by every constructor that accepts a path ([`Self::with()`],
+    /// [`Self::try_with()`], the override variants and the macros). With
+    /// [`AbsolutePolicy::Reject`], fallible constructors return
+    /// [`AppPathError::AbsolutePathRejected`](crate::AppPathError::AbsolutePathRejected)
+    /// and panicking constructors panic.
+    ///
+    /// **Set the policy early**, before other threads start creating paths. Changing it
+    /// later is thread-safe but paths created earlier are not re-checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AbsolutePolicy, AppPath};
+    ///
+    /// AppPath::set_absolute_policy(AbsolutePolicy::Reject);
+    ///
+    /// assert!(AppPath::try_with("config.toml").is_ok());
+    /// assert!(AppPath::try_with(std::env::temp_dir()).is_err());
+    /// # AppPath::set_absolute_policy(AbsolutePolicy::Allow);
+    /// ```
+    pub fn set_absolute_policy(policy: AbsolutePolicy) {
+        policy::set_absolute_policy(policy);
+    }
+
+    /// Returns the current process-wide policy for absolute path inputs.
+    ///
+    /// Defaults to [`AbsolutePolicy::Allow`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AbsolutePolicy, AppPath};
+    ///
+    /// if AppPath::absolute_policy() == AbsolutePolicy::Allow {
+    ///     println!("Absolute paths are used as-is");
+    /// }
+    /// ```
+    pub fn absolute_policy() -> AbsolutePolicy {
+        policy::absolute_policy()
+    }
+
+    /// Sets the observer invoked for absolute inputs under [`AbsolutePolicy::Warn`].
+    ///
+    /// Install an observer before turning on [`AbsolutePolicy::Warn`]; without one,
+    /// warnings are dropped. Pass `None` to remove the observer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AbsolutePolicy, AppPath};
+    /// use std::path::Path;
+    ///
+    /// fn report(path: &Path) {
+    ///     println!("warning: non-portable path {}", path.display());
+    /// }
+    ///
+    /// AppPath::set_absolute_observer(Some(report));
+    /// AppPath::set_absolute_policy(AbsolutePolicy::Warn);
+    /// # AppPath::set_absolute_policy(AbsolutePolicy::Allow);
+    /// ```
+    pub fn set_absolute_observer(observer: Option<fn(&Path)>) {
+        policy::set_absolute_observer(observer);
+    }
+}
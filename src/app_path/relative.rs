@@ -0,0 +1,145 @@
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+
+use crate::{AppPath, AppPathError};
+
+/// A path relative to the application's base directory, resolved on demand.
+///
+/// Created by [`AppPath::strip_base()`] or [`Self::try_new()`]. Where an [`AppPath`]
+/// is tied to the base directory of the machine it was created on, a
+/// `RelativeAppPath` only stores the part below it, so it can be saved, sent to
+/// another installation, and resolved there with [`Self::resolve()`].
+///
+/// [`Display`](fmt::Display) and, with the `serde` feature, `Serialize` always use `/`
+/// separators, so the stored form is the same on every platform.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, RelativeAppPath};
+///
+/// let db = AppPath::with("data/users.db");
+/// let relative = db.strip_base().unwrap();
+/// assert_eq!(relative.to_string(), "data/users.db");
+///
+/// // Later, possibly on another machine
+/// let restored = RelativeAppPath::try_new(relative.to_string())?;
+/// assert_eq!(restored.resolve(), AppPath::with("data/users.db"));
+/// # Ok::<(), app_path::AppPathError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RelativeAppPath {
+    relative: PathBuf,
+}
+
+impl AppPath {
+    /// Returns the part of this path below the base directory as a [`RelativeAppPath`].
+    ///
+    /// The owned counterpart of [`Self::relative_to_base()`], returning `None` in the
+    /// same cases: when the path lies outside the base directory or the base directory
+    /// cannot be determined.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config/app.toml");
+    /// let relative = config.strip_base().unwrap();
+    /// assert_eq!(relative.resolve(), config);
+    /// ```
+    pub fn strip_base(&self) -> Option<RelativeAppPath> {
+        self.relative_to_base().map(|relative| RelativeAppPath {
+            relative: relative.to_path_buf(),
+        })
+    }
+}
+
+impl RelativeAppPath {
+    /// Creates a relative path from stored input, e.g. a value read from a manifest.
+    ///
+    /// Both `/` and the platform's native separator are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::AbsolutePathRejected`] if `path` is absolute or starts at
+    /// a root, since it could not be resolved against a base directory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::RelativeAppPath;
+    ///
+    /// assert!(RelativeAppPath::try_new("plugins/audio.so").is_ok());
+    /// assert!(RelativeAppPath::try_new("/etc/passwd").is_err());
+    /// ```
+    pub fn try_new(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let path = path.as_ref();
+        if matches!(
+            path.components().next(),
+            Some(Component::Prefix(_) | Component::RootDir)
+        ) {
+            return Err(AppPathError::AbsolutePathRejected(path.to_path_buf()));
+        }
+        Ok(Self {
+            relative: path.to_path_buf(),
+        })
+    }
+
+    /// Resolves the path against the current application's base directory.
+    ///
+    /// # Panics
+    ///
+    /// Panics in any situation where [`Self::try_resolve()`] returns an error.
+    pub fn resolve(&self) -> AppPath {
+        AppPath::with(&self.relative)
+    }
+
+    /// Resolves the path against the current application's base directory (fallible).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AppPath::try_with()`].
+    pub fn try_resolve(&self) -> Result<AppPath, AppPathError> {
+        AppPath::try_with(&self.relative)
+    }
+
+    /// Returns the relative path.
+    #[inline]
+    pub fn as_path(&self) -> &Path {
+        &self.relative
+    }
+
+    /// Consumes the wrapper and returns the relative `PathBuf`.
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf {
+        self.relative
+    }
+}
+
+/// Renders the path with `/` separators on every platform.
+impl fmt::Display for RelativeAppPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, component) in self.relative.components().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            write!(f, "{}", component.as_os_str().to_string_lossy())?;
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<Path> for RelativeAppPath {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        &self.relative
+    }
+}
+
+impl From<RelativeAppPath> for PathBuf {
+    #[inline]
+    fn from(relative: RelativeAppPath) -> Self {
+        relative.relative
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{AppPath, AppPathError, RelativeAppPath};
+
+impl AppPath {
+    /// Creates a path whose override comes from a field of a deserialized config struct.
+    ///
+    /// Formalizes the "config file points to the data directory" pattern: `extract` pulls
+    /// an optional override string out of `config`. If it returns `Some`, that value is
+    /// resolved like any override; otherwise `default` is used. Empty or whitespace-only
+    /// strings count as unset, exactly like [`Self::with_override()`]. `config` is usually
+    /// deserialized from the app's config file, but any type works.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics only if the application's base directory cannot be determined.
+    /// See [`Self::with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     data_dir: Option<String>,
+    /// }
+    ///
+    /// let config = Config { data_dir: Some("shared/data".into()) };
+    /// let data = AppPath::with_override_from_config("data", &config, |c| c.data_dir.as_deref());
+    /// assert_eq!(data, AppPath::with("shared/data"));
+    /// ```
+    pub fn with_override_from_config<T, F>(
+        default: impl AsRef<Path>,
+        config: &T,
+        extract: F,
+    ) -> Self
+    where
+        F: Fn(&T) -> Option<&str>,
+    {
+        Self::with_override(default, extract(config))
+    }
+
+    /// Creates a path whose override comes from a config struct field (fallible).
+    ///
+    /// Fallible version of [`Self::with_override_from_config()`].
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError`] if the application's base directory cannot be determined.
+    /// See [`Self::try_with()`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Config {
+    ///     log_file: Option<String>,
+    /// }
+    ///
+    /// let config = Config { log_file: None };
+    /// let log = AppPath::try_with_override_from_config("logs/app.log", &config, |c| {
+    ///     c.log_file.as_deref()
+    /// })?;
+    /// assert_eq!(log, AppPath::with("logs/app.log"));
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn try_with_override_from_config<T, F>(
+        default: impl AsRef<Path>,
+        config: &T,
+        extract: F,
+    ) -> Result<Self, AppPathError>
+    where
+        F: Fn(&T) -> Option<&str>,
+    {
+        Self::try_with_override(default, extract(config))
+    }
+}
+
+/// Serializes the resolved (full) path.
+///
+/// Requires the `serde` feature. Like [`Path`]'s own implementation, this fails for
+/// paths that are not valid UTF-8.
+impl Serialize for AppPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.full_path.serialize(serializer)
+    }
+}
+
+/// Deserializes a path string through [`AppPath::try_with()`].
+///
+/// Relative values in a config file therefore resolve against the application's base
+/// directory, while absolute values pass through unchanged. Serializing and
+/// deserializing again yields an equal `AppPath`.
+///
+/// Requires the `serde` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::AppPath;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     data_dir: AppPath,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{ "data_dir": "data" }"#)?;
+/// assert_eq!(config.data_dir, AppPath::with("data"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+impl<'de> Deserialize<'de> for AppPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = PathBuf::deserialize(deserializer)?;
+        AppPath::try_with(path).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes the relative path with `/` separators, as rendered by its `Display`.
+///
+/// Requires the `serde` feature. Fails for paths that are not valid UTF-8.
+impl Serialize for RelativeAppPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.as_path().to_str().is_none() {
+            return Err(serde::ser::Error::custom(
+                "path contains invalid UTF-8 characters",
+            ));
+        }
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a relative path string through [`RelativeAppPath::try_new()`].
+///
+/// Requires the `serde` feature. Absolute values are rejected.
+impl<'de> Deserialize<'de> for RelativeAppPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let path = PathBuf::deserialize(deserializer)?;
+        RelativeAppPath::try_new(path).map_err(de::Error::custom)
+    }
+}
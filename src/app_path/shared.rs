@@ -0,0 +1,212 @@
+use std::borrow::Borrow;
+use std::ffi::OsStr;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::constructors::check_input;
+use crate::{try_exe_dir, AppPath, AppPathError};
+
+/// A reference-counted, immutable [`AppPath`] whose `Clone` never allocates.
+///
+/// Created by [`AppPath::into_shared()`] or [`AppPathShared::with()`]. Cloning an
+/// [`AppPath`] copies its path, which adds up when the same deep path is handed to many
+/// tasks or threads. An `AppPathShared` stores the path in an `Arc<Path>` instead, so
+/// clones only bump a reference count and all of them share one allocation.
+///
+/// It dereferences to [`Path`] and implements the same comparison, hashing and
+/// conversion traits as [`AppPath`]. Convert back with [`Self::to_app_path()`] when a
+/// mutable path or the `AppPath` helpers are needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, AppPathShared};
+///
+/// let report = AppPath::with("reports/2024/q4/summary.pdf").into_shared();
+///
+/// let for_task = report.clone(); // no allocation
+/// assert!(AppPathShared::ptr_eq(&report, &for_task));
+/// assert_eq!(for_task.extension(), Some("pdf".as_ref()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AppPathShared {
+    full_path: Arc<Path>,
+}
+
+impl AppPath {
+    /// Converts this path into an [`AppPathShared`] whose clones share one allocation.
+    ///
+    /// Copies the path once into a reference-counted buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let config = AppPath::with("config.toml");
+    /// let shared = config.clone().into_shared();
+    /// assert_eq!(&*shared, &*config);
+    /// ```
+    pub fn into_shared(self) -> AppPathShared {
+        AppPathShared {
+            full_path: Arc::from(self.full_path),
+        }
+    }
+}
+
+impl AppPathShared {
+    /// Creates a shared path relative to the application's base directory (fallible).
+    ///
+    /// Resolves `path` exactly like [`AppPath::try_with()`], with the same errors. With
+    /// the `cache` feature, repeated calls with the same input return clones of one cached
+    /// allocation, so a hit allocates nothing at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`AppPath::try_with()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::{AppPath, AppPathShared};
+    ///
+    /// let template = AppPathShared::try_with("templates/index.html")?;
+    /// assert_eq!(template, AppPath::with("templates/index.html"));
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn try_with(path: impl AsRef<Path>) -> Result<Self, AppPathError> {
+        let exe_dir = try_exe_dir()?;
+        let path = path.as_ref();
+        check_input(path)?;
+        #[cfg(feature = "cache")]
+        let full_path = crate::cache::resolve(exe_dir, path);
+        #[cfg(not(feature = "cache"))]
+        let full_path = Arc::from(exe_dir.join(path));
+        Ok(Self { full_path })
+    }
+
+    /// Creates a shared path relative to the application's base directory.
+    ///
+    /// The shared counterpart of [`AppPath::with()`], meant for hot loops that resolve the
+    /// same path over and over; see [`Self::try_with()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`AppPath::with()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPathShared;
+    ///
+    /// for _ in 0..3 {
+    ///     let template = AppPathShared::with("templates/index.html");
+    ///     assert!(template.ends_with("templates/index.html"));
+    /// }
+    /// ```
+    pub fn with(path: impl AsRef<Path>) -> Self {
+        match Self::try_with(path) {
+            Ok(shared) => shared,
+            Err(e) => panic!("Failed to create AppPath: {e}"),
+        }
+    }
+
+    /// Returns `true` if both values share the same allocation.
+    ///
+    /// Clones of one `AppPathShared` always do; separately converted paths never do,
+    /// even when they are equal.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.full_path, &other.full_path)
+    }
+
+    /// Copies the path into a new, independently owned [`AppPath`].
+    #[inline]
+    pub fn to_app_path(&self) -> AppPath {
+        AppPath {
+            full_path: self.full_path.to_path_buf(),
+        }
+    }
+
+    /// Copies the path into an owned `PathBuf`.
+    ///
+    /// Unlike [`AppPath::into_path_buf()`] this always allocates, since the storage
+    /// may be shared with other clones.
+    #[inline]
+    pub fn into_path_buf(self) -> PathBuf {
+        self.full_path.to_path_buf()
+    }
+}
+
+impl std::fmt::Display for AppPathShared {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.full_path.display())
+    }
+}
+
+impl Deref for AppPathShared {
+    type Target = Path;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.full_path
+    }
+}
+
+impl AsRef<Path> for AppPathShared {
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        &self.full_path
+    }
+}
+
+impl AsRef<OsStr> for AppPathShared {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        self.full_path.as_os_str()
+    }
+}
+
+impl Borrow<Path> for AppPathShared {
+    #[inline]
+    fn borrow(&self) -> &Path {
+        &self.full_path
+    }
+}
+
+impl From<AppPath> for AppPathShared {
+    #[inline]
+    fn from(app_path: AppPath) -> Self {
+        app_path.into_shared()
+    }
+}
+
+impl From<AppPathShared> for AppPath {
+    #[inline]
+    fn from(shared: AppPathShared) -> Self {
+        shared.to_app_path()
+    }
+}
+
+impl From<AppPathShared> for PathBuf {
+    #[inline]
+    fn from(shared: AppPathShared) -> Self {
+        shared.into_path_buf()
+    }
+}
+
+impl PartialEq<AppPath> for AppPathShared {
+    #[inline]
+    fn eq(&self, other: &AppPath) -> bool {
+        *self.full_path == *other.full_path
+    }
+}
+
+impl PartialEq<AppPathShared> for AppPath {
+    #[inline]
+    fn eq(&self, other: &AppPathShared) -> bool {
+        *self.full_path == *other.full_path
+    }
+}
@@ -0,0 +1,44 @@
+use std::path::PathBuf;
+
+use crate::functions::{clear_exe_dir_override, set_exe_dir_override};
+use crate::AppPath;
+
+impl AppPath {
+    /// Replaces the application's base directory for tests.
+    ///
+    /// After this call, every path resolution ([`Self::new()`], [`Self::with()`], the
+    /// macros, ...) uses `dir` as the base instead of the executable's directory, so
+    /// tests can point the base at a temporary directory and assert on exact paths.
+    /// Call [`Self::clear_exe_dir_override()`] to restore normal resolution.
+    ///
+    /// **Not for production use.** The override is process-wide: it is thread-safe, but
+    /// affects tests running concurrently in the same process, so tests relying on it
+    /// should run serially (e.g. `--test-threads=1` or a shared lock). Each call leaks
+    /// the given path, which is harmless in tests but unsuitable for repeated use
+    /// elsewhere.
+    ///
+    /// Requires the `testing` feature; enable it only in `[dev-dependencies]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let sandbox = std::env::temp_dir().join("myapp_test_base");
+    /// AppPath::set_exe_dir_for_testing(sandbox.clone());
+    ///
+    /// assert_eq!(&*AppPath::with("config.toml"), sandbox.join("config.toml"));
+    ///
+    /// AppPath::clear_exe_dir_override();
+    /// ```
+    pub fn set_exe_dir_for_testing(dir: PathBuf) {
+        set_exe_dir_override(dir);
+    }
+
+    /// Removes an override installed by [`Self::set_exe_dir_for_testing()`].
+    ///
+    /// Does nothing if no override is set. Requires the `testing` feature.
+    pub fn clear_exe_dir_override() {
+        clear_exe_dir_override();
+    }
+}
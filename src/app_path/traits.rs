@@ -3,9 +3,10 @@
 //! This module contains all the standard trait implementations that make `AppPath`
 //! work seamlessly with Rust's standard library and idiomatic code patterns.
 
-use crate::AppPath;
+use crate::{AppPath, AppPathError};
 use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::ffi::{OsStr, OsString};
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
@@ -94,6 +95,41 @@ impl From<&PathBuf> for AppPath {
     }
 }
 
+// === Fallible OS String Conversions ===
+
+impl TryFrom<OsString> for AppPath {
+    type Error = AppPathError;
+
+    /// Resolves an OS string, e.g. from [`std::env::args_os()`], like [`AppPath::try_with()`].
+    ///
+    /// No UTF-8 conversion takes place, so non-UTF-8 values are preserved exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::ffi::OsString;
+    ///
+    /// let config = AppPath::try_from(OsString::from("config.toml"))?;
+    /// assert_eq!(config, AppPath::with("config.toml"));
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    #[inline]
+    fn try_from(path: OsString) -> Result<Self, Self::Error> {
+        Self::try_with(path)
+    }
+}
+
+impl TryFrom<&OsStr> for AppPath {
+    type Error = AppPathError;
+
+    /// Resolves a borrowed OS string like [`AppPath::try_with()`], without UTF-8 conversion.
+    #[inline]
+    fn try_from(path: &OsStr) -> Result<Self, Self::Error> {
+        Self::try_with(path)
+    }
+}
+
 // === Additional Trait Implementations ===
 
 impl PartialEq for AppPath {
@@ -279,6 +315,29 @@ impl AsRef<std::ffi::OsStr> for AppPath {
     }
 }
 
+impl AsRef<AppPath> for AppPath {
+    /// Returns `self`, so generic code bounded by `P: AsRef<AppPath>` accepts both
+    /// `AppPath` and `&AppPath`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// fn file_name<P: AsRef<AppPath>>(path: P) -> String {
+    ///     path.as_ref().file_name().unwrap().to_string_lossy().into_owned()
+    /// }
+    ///
+    /// let config = AppPath::with("config.toml");
+    /// assert_eq!(file_name(&config), "config.toml");
+    /// assert_eq!(file_name(config), "config.toml");
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &AppPath {
+        self
+    }
+}
+
 impl From<AppPath> for PathBuf {
     /// Converts `AppPath` to `PathBuf` for owned path operations.
     ///
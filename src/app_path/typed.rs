@@ -0,0 +1,77 @@
+use typed_path::{NativePathBuf, UnixEncoding, UnixPathBuf, WindowsEncoding, WindowsPathBuf};
+
+use crate::AppPath;
+
+impl AppPath {
+    /// Returns the path as a `typed-path` path of the platform's own flavor.
+    ///
+    /// This is a [`UnixPathBuf`] on Unix and a [`WindowsPathBuf`] on Windows. On Unix
+    /// the raw bytes are kept, so non-UTF-8 paths survive; elsewhere the path's text is
+    /// used, with non-UTF-8 sequences replaced as in [`Path::to_string_lossy`].
+    ///
+    /// Requires the `typed-path` feature.
+    ///
+    /// [`Path::to_string_lossy`]: std::path::Path::to_string_lossy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let dist = AppPath::with("dist");
+    /// assert!(dist.to_typed_native().ends_with("dist"));
+    /// ```
+    pub fn to_typed_native(&self) -> NativePathBuf {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            NativePathBuf::from(self.full_path.as_os_str().as_bytes())
+        }
+        #[cfg(not(unix))]
+        {
+            NativePathBuf::from(&*self.full_path.to_string_lossy())
+        }
+    }
+
+    /// Converts the path's text into a Windows-flavored `typed-path` path.
+    ///
+    /// Separators are rewritten to `\` and the components are kept, so
+    /// `/srv/app/dist` becomes `\srv\app\dist` on a Unix host. Unlike building a
+    /// [`WindowsPathBuf`] from [`Self::to_bytes()`], this never misreads the platform's
+    /// byte encoding. Useful for emitting paths for another platform, e.g. in build
+    /// scripts or installers.
+    ///
+    /// Requires the `typed-path` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let dist = AppPath::with("dist/app.exe");
+    /// let windows = dist.to_typed_windows();
+    /// assert!(windows.ends_with(r"dist\app.exe"));
+    /// ```
+    pub fn to_typed_windows(&self) -> WindowsPathBuf {
+        self.to_typed_native().with_encoding::<WindowsEncoding>()
+    }
+
+    /// Converts the path's text into a Unix-flavored `typed-path` path.
+    ///
+    /// Separators are rewritten to `/`. A Windows prefix such as `C:` has no Unix
+    /// equivalent and is dropped, so `C:\app\dist` becomes `/app/dist`.
+    ///
+    /// Requires the `typed-path` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let dist = AppPath::with("dist/app");
+    /// assert!(dist.to_typed_unix().ends_with("dist/app"));
+    /// ```
+    pub fn to_typed_unix(&self) -> UnixPathBuf {
+        self.to_typed_native().with_encoding::<UnixEncoding>()
+    }
+}
@@ -0,0 +1,46 @@
+use camino::Utf8PathBuf;
+
+use crate::{AppPath, AppPathError};
+
+impl AppPath {
+    /// Returns the path as a [`Utf8PathBuf`], or `None` if it is not valid UTF-8.
+    ///
+    /// For interop with camino-based APIs, e.g. web frameworks serving static files.
+    ///
+    /// Requires the `camino` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let assets = AppPath::with("assets");
+    /// let utf8 = assets.to_utf8().expect("ASCII path is valid UTF-8");
+    /// assert_eq!(utf8.as_std_path(), &*assets);
+    /// ```
+    pub fn to_utf8(&self) -> Option<Utf8PathBuf> {
+        Utf8PathBuf::from_path_buf(self.full_path.clone()).ok()
+    }
+
+    /// Converts the path into a [`Utf8PathBuf`] without copying.
+    ///
+    /// Requires the `camino` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AppPathError::NonUtf8Path`] with the original path if it is not valid
+    /// UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    ///
+    /// let static_dir = AppPath::with("web/static").try_into_utf8()?;
+    /// assert!(static_dir.as_str().ends_with("static"));
+    /// # Ok::<(), app_path::AppPathError>(())
+    /// ```
+    pub fn try_into_utf8(self) -> Result<Utf8PathBuf, AppPathError> {
+        Utf8PathBuf::from_path_buf(self.full_path).map_err(AppPathError::NonUtf8Path)
+    }
+}
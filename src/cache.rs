@@ -0,0 +1,70 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+// Independent shards, so threads resolving different inputs rarely contend for a lock
+const SHARD_COUNT: usize = 16;
+
+// Upper bound on cached inputs per shard, so dynamically built paths cannot grow the
+// cache forever; 1024 inputs in total
+const MAX_ENTRIES_PER_SHARD: usize = 64;
+
+// Only used as the repeat operand of `SHARDS`; each element is a separate mutex
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_SHARD: Mutex<Shard> = Mutex::new(Shard {
+    base: None,
+    paths: None,
+});
+
+// Resolved paths by input, valid for the base directory they were joined onto
+static SHARDS: [Mutex<Shard>; SHARD_COUNT] = [EMPTY_SHARD; SHARD_COUNT];
+
+struct Shard {
+    base: Option<&'static Path>,
+    paths: Option<HashMap<PathBuf, Arc<Path>>>,
+}
+
+/// Joins `path` onto `base`, reusing an earlier result for the same input.
+///
+/// A hit returns the cached `Arc<Path>` without allocating. Inputs are spread over
+/// [`SHARD_COUNT`] independently locked shards by hash, and each lock is only held for
+/// a map lookup or insert, never for the join itself. When a shard holds
+/// [`MAX_ENTRIES_PER_SHARD`] inputs it starts over, so inputs that are still hot get
+/// cached again on their next use. A shard is dropped whenever `base` changes (only
+/// possible through the `testing` override), compared by address since base
+/// directories are `'static`.
+pub(crate) fn resolve(base: &'static Path, path: &Path) -> Arc<Path> {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    let shard = &SHARDS[hasher.finish() as usize % SHARD_COUNT];
+
+    if let Some(full_path) = lookup(shard, base, path) {
+        return full_path;
+    }
+
+    let full_path: Arc<Path> = Arc::from(base.join(path));
+    let mut shard = shard.lock().unwrap_or_else(|e| e.into_inner());
+    let paths = shard.paths_for(base);
+    if paths.len() >= MAX_ENTRIES_PER_SHARD {
+        paths.clear();
+    }
+    paths.entry(path.to_path_buf()).or_insert(full_path).clone()
+}
+
+fn lookup(shard: &Mutex<Shard>, base: &'static Path, path: &Path) -> Option<Arc<Path>> {
+    let mut shard = shard.lock().unwrap_or_else(|e| e.into_inner());
+    shard.paths_for(base).get(path).cloned()
+}
+
+impl Shard {
+    /// Returns the cached paths, first dropping them if they were joined onto another base.
+    fn paths_for(&mut self, base: &'static Path) -> &mut HashMap<PathBuf, Arc<Path>> {
+        if !self.base.is_some_and(|cached| std::ptr::eq(cached, base)) {
+            self.base = Some(base);
+            self.paths = None;
+        }
+        self.paths.get_or_insert_with(HashMap::new)
+    }
+}
@@ -0,0 +1,150 @@
+use std::ffi::OsStr;
+use std::path::{Component, Path, PathBuf};
+
+/// How the running application appears to be deployed.
+///
+/// Returned by [`crate::AppPath::deployment_mode()`]. Applications can use this to
+/// choose a storage strategy, for example keeping data beside the executable when
+/// running portably but falling back to per-user directories when installed.
+///
+/// Detection is **best-effort** and purely heuristic. See
+/// [`crate::AppPath::deployment_mode()`] for the exact rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, DeploymentMode};
+///
+/// match AppPath::deployment_mode() {
+///     DeploymentMode::Portable => println!("Storing data beside the executable"),
+///     DeploymentMode::Installed => println!("Storing data in the user profile"),
+///     DeploymentMode::Development => println!("Running from a cargo build"),
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeploymentMode {
+    /// Running from a self-contained, writable folder outside system locations.
+    Portable,
+
+    /// Running from a system install location or a read-only directory.
+    Installed,
+
+    /// Running from a cargo build output directory or next to a `Cargo.toml`.
+    Development,
+}
+
+/// Unix prefixes that indicate a system-managed installation.
+#[cfg(not(windows))]
+const SYSTEM_PREFIXES: &[&str] = &[
+    "/usr",
+    "/opt",
+    "/bin",
+    "/sbin",
+    "/snap",
+    "/nix/store",
+    "/Applications",
+    "/Library",
+    "/System",
+];
+
+/// Windows directory names that indicate a system-managed installation.
+#[cfg(windows)]
+const SYSTEM_DIR_NAMES: &[&str] = &["Program Files", "Program Files (x86)", "Windows"];
+
+/// How many ancestors of the base directory are checked for a `Cargo.toml`.
+const CARGO_MANIFEST_SEARCH_DEPTH: usize = 3;
+
+/// Infers the deployment mode for the given base directory.
+///
+/// Checks are applied in order: development layout first, then system install
+/// prefixes, then writability of the base directory.
+pub(crate) fn detect_deployment_mode(base: &Path) -> DeploymentMode {
+    if is_development_layout(base) {
+        DeploymentMode::Development
+    } else if has_system_prefix(base) || !is_writable_dir(base) {
+        DeploymentMode::Installed
+    } else {
+        DeploymentMode::Portable
+    }
+}
+
+fn is_development_layout(base: &Path) -> bool {
+    // Matches `target/debug`, `target/release` and `target/<triple>/debug` layouts
+    let names: Vec<&OsStr> = base
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        })
+        .collect();
+    let in_target_profile = names.iter().enumerate().any(|(i, name)| {
+        *name == "target"
+            && names[i + 1..]
+                .iter()
+                .take(2)
+                .any(|profile| *profile == "debug" || *profile == "release")
+    });
+
+    in_target_profile
+        || base
+            .ancestors()
+            .take(CARGO_MANIFEST_SEARCH_DEPTH)
+            .any(|dir| dir.join("Cargo.toml").is_file())
+}
+
+#[cfg(not(windows))]
+fn has_system_prefix(base: &Path) -> bool {
+    SYSTEM_PREFIXES
+        .iter()
+        .any(|prefix| base.starts_with(prefix))
+}
+
+#[cfg(windows)]
+fn has_system_prefix(base: &Path) -> bool {
+    base.components().any(|component| match component {
+        Component::Normal(name) => SYSTEM_DIR_NAMES
+            .iter()
+            .any(|dir| name.to_string_lossy().eq_ignore_ascii_case(dir)),
+        _ => false,
+    })
+}
+
+fn is_writable_dir(base: &Path) -> bool {
+    std::fs::metadata(base)
+        .map(|metadata| metadata.is_dir() && !metadata.permissions().readonly())
+        .unwrap_or(false)
+}
+
+/// Finds the cargo project root for a base directory inside a development layout.
+///
+/// For `<root>/target/.../debug` layouts this is `<root>` (when it holds a `Cargo.toml`);
+/// otherwise the nearest ancestor within the manifest search depth holding a `Cargo.toml`.
+pub(crate) fn project_root_for(base: &Path) -> Option<PathBuf> {
+    let from_target_dir = base
+        .ancestors()
+        .filter(|dir| dir.file_name().is_some_and(|name| name == "target"))
+        .filter_map(Path::parent)
+        .find(|root| root.join("Cargo.toml").is_file());
+
+    from_target_dir
+        .or_else(|| {
+            base.ancestors()
+                .take(CARGO_MANIFEST_SEARCH_DEPTH)
+                .find(|dir| dir.join("Cargo.toml").is_file())
+        })
+        .map(Path::to_path_buf)
+}
+
+/// Resolves a bundled resource, preferring the base directory and falling back to the
+/// project root when running from a development layout.
+pub(crate) fn resolve_resource(base: &Path, rel: &Path) -> PathBuf {
+    let beside_exe = base.join(rel);
+    if beside_exe.exists() {
+        return beside_exe;
+    }
+
+    project_root_for(base)
+        .map(|root| root.join(rel))
+        .filter(|in_project| in_project.exists())
+        .unwrap_or(beside_exe)
+}
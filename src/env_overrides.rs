@@ -0,0 +1,39 @@
+use std::ffi::OsStr;
+use std::sync::Mutex;
+
+// Environment variables consulted for overrides, in first-registration order
+static ENV_OVERRIDES: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+/// Records a variable name, ignoring duplicates.
+pub(crate) fn register(var: &'static str) {
+    let mut vars = ENV_OVERRIDES.lock().unwrap_or_else(|e| e.into_inner());
+    if !vars.contains(&var) {
+        vars.push(var);
+    }
+}
+
+/// Records a variable name that is not `'static`.
+///
+/// The name is leaked the first time it is seen, so memory use is bounded by the number
+/// of distinct variables an application consults.
+pub(crate) fn register_dynamic(var: &str) {
+    let mut vars = ENV_OVERRIDES.lock().unwrap_or_else(|e| e.into_inner());
+    if !vars.contains(&var) {
+        vars.push(Box::leak(var.to_owned().into_boxed_str()));
+    }
+}
+
+pub(crate) fn registered() -> Vec<&'static str> {
+    ENV_OVERRIDES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Registers `var` and reads it. Used by the `env =` arms of the macros.
+#[doc(hidden)]
+pub fn __env_override(var: impl AsRef<OsStr>) -> Option<String> {
+    let var = var.as_ref();
+    register_dynamic(&var.to_string_lossy());
+    std::env::var(var).ok()
+}
@@ -17,6 +17,24 @@ use std::path::PathBuf;
 ///   - Extremely rare, indicates a corrupted or broken system
 ///   - May occur with custom or non-standard program loaders
 ///
+/// - **`AbsolutePathRejected`**: When an absolute input is rejected
+///   - Occurs after opting in with [`crate::AbsolutePolicy::Reject`]
+///   - Returned by APIs that only accept relative input, such as
+///     [`crate::RelativeAppPath::try_new()`] and [`crate::AppPath::join_checked()`]
+///
+/// - **`PathEscapesBase`**: When a path is required to lie under a root but does not
+///   - Returned by containment checks such as [`crate::AppPath::require_relative_to()`]
+///     and [`crate::AppPath::try_with_contained()`]
+///
+/// - **`InvalidPath`**: When an input path can never be valid
+///   - Paths containing an interior NUL byte, e.g. from untrusted input
+///
+/// - **`ParentNotFound`**: When a path's parent directory is required to exist but doesn't
+///   - Returned by [`crate::AppPath::with_existing_parent()`]
+///
+/// - **`NonUtf8Path`**: When a path must be valid UTF-8 but isn't
+///   - Returned by UTF-8 conversions such as `AppPath::try_into_utf8()` (`camino` feature)
+///
 /// - **`IoError`**: When I/O operations fail
 ///   - Directory creation fails due to insufficient permissions
 ///   - Disk space issues or filesystem errors
@@ -57,9 +75,11 @@ use std::path::PathBuf;
 ///             _ => eprintln!("Other I/O error: {io_err}"),
 ///         }
 ///     }
+///     Err(e) => eprintln!("Failed to resolve path: {e}"),
 /// }
 /// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum AppPathError {
     /// Failed to determine the current executable path.
     ///
@@ -73,6 +93,51 @@ pub enum AppPathError {
     /// which is extremely rare and indicates a corrupted or broken system.
     InvalidExecutablePath(String),
 
+    /// An absolute input path was rejected by the process-wide policy.
+    ///
+    /// This error occurs when [`crate::AbsolutePolicy::Reject`] has been set with
+    /// [`crate::AppPath::set_absolute_policy()`], and from APIs that only accept relative
+    /// input, such as [`crate::AppPath::join_checked()`]. Contains the rejected input path.
+    AbsolutePathRejected(PathBuf),
+
+    /// A path was required to lie under a root directory but does not.
+    ///
+    /// Returned by containment checks such as
+    /// [`crate::AppPath::require_relative_to()`],
+    /// [`crate::AppPath::try_with_contained()`] and [`crate::AppPath::join_checked()`].
+    /// The check is lexical, so `..` components that climb above the root also count as
+    /// escaping.
+    PathEscapesBase {
+        /// The offending path.
+        path: PathBuf,
+        /// The root it was expected to stay under.
+        base: PathBuf,
+    },
+
+    /// An input path is invalid on every platform.
+    ///
+    /// Returned by constructors such as [`crate::AppPath::try_with()`] for paths that
+    /// contain an interior NUL byte, which the OS would reject only once the path is
+    /// used. The panicking constructors panic with the same message.
+    InvalidPath {
+        /// The rejected input path.
+        path: PathBuf,
+        /// Why the path is invalid.
+        reason: String,
+    },
+
+    /// The parent directory of a path does not exist.
+    ///
+    /// Returned by [`crate::AppPath::with_existing_parent()`], which deliberately does
+    /// not create missing directories. Contains the missing parent directory.
+    ParentNotFound(PathBuf),
+
+    /// A path is not valid UTF-8 but a UTF-8 path was required.
+    ///
+    /// Returned by UTF-8 conversions such as `AppPath::try_into_utf8()` (`camino`
+    /// feature). Contains the offending path.
+    NonUtf8Path(PathBuf),
+
     /// An I/O operation failed.
     ///
     /// This error occurs when filesystem operations fail, such as:
@@ -88,6 +153,31 @@ pub enum AppPathError {
     IoError(std::io::Error),
 }
 
+impl AppPathError {
+    /// Returns the [`std::io::ErrorKind`] of an [`AppPathError::IoError`], or `None` for
+    /// any other variant.
+    ///
+    /// The kind survives the path context added by this crate's file operations, so
+    /// callers can branch on it without matching the variant first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use app_path::AppPath;
+    /// use std::io::ErrorKind;
+    ///
+    /// let missing = AppPath::with("io_kind_example_missing.toml");
+    /// let err = missing.read_to_string().unwrap_err();
+    /// assert_eq!(err.io_kind(), Some(ErrorKind::NotFound));
+    /// ```
+    pub fn io_kind(&self) -> Option<std::io::ErrorKind> {
+        match self {
+            AppPathError::IoError(err) => Some(err.kind()),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for AppPathError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -97,6 +187,30 @@ impl std::fmt::Display for AppPathError {
             AppPathError::InvalidExecutablePath(msg) => {
                 write!(f, "Invalid executable path: {msg}")
             }
+            AppPathError::AbsolutePathRejected(path) => {
+                write!(
+                    f,
+                    "Absolute path rejected by policy: {} (use a path relative to the application directory)",
+                    path.display()
+                )
+            }
+            AppPathError::PathEscapesBase { path, base } => {
+                write!(
+                    f,
+                    "Path escapes base directory: {} is not under {}",
+                    path.display(),
+                    base.display()
+                )
+            }
+            AppPathError::InvalidPath { path, reason } => {
+                write!(f, "Invalid path {path:?}: {reason}")
+            }
+            AppPathError::ParentNotFound(parent) => {
+                write!(f, "Parent directory does not exist: {}", parent.display())
+            }
+            AppPathError::NonUtf8Path(path) => {
+                write!(f, "Path is not valid UTF-8: {}", path.display())
+            }
             AppPathError::IoError(err) => {
                 write!(f, "I/O operation failed: {err}")
             }
@@ -119,6 +233,49 @@ impl From<std::io::Error> for AppPathError {
     }
 }
 
+/// Converts back into [`std::io::Error`], so `?` works in functions returning
+/// [`std::io::Result`].
+///
+/// [`AppPathError::IoError`] yields the wrapped error unchanged, keeping its kind and
+/// message. Other variants are wrapped with a fitting kind, and their message is
+/// preserved:
+///
+/// | Variant | `io::ErrorKind` |
+/// |---------|-----------------|
+/// | `ExecutableNotFound`, `InvalidExecutablePath` | `Other` |
+/// | `AbsolutePathRejected`, `PathEscapesBase`, `InvalidPath` | `InvalidInput` |
+/// | `ParentNotFound` | `NotFound` |
+/// | `NonUtf8Path` | `InvalidData` |
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::AppPath;
+///
+/// fn load_config() -> std::io::Result<String> {
+///     let config = AppPath::try_with("config.toml")?;
+///     std::fs::read_to_string(config)
+/// }
+/// ```
+impl From<AppPathError> for std::io::Error {
+    fn from(err: AppPathError) -> Self {
+        use std::io::ErrorKind;
+
+        let kind = match err {
+            AppPathError::IoError(io_err) => return io_err,
+            AppPathError::ExecutableNotFound(_) | AppPathError::InvalidExecutablePath(_) => {
+                ErrorKind::Other
+            }
+            AppPathError::AbsolutePathRejected(_)
+            | AppPathError::PathEscapesBase { .. }
+            | AppPathError::InvalidPath { .. } => ErrorKind::InvalidInput,
+            AppPathError::ParentNotFound(_) => ErrorKind::NotFound,
+            AppPathError::NonUtf8Path(_) => ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, err)
+    }
+}
+
 /// Creates an IoError with path context for better debugging.
 ///
 /// This implementation adds the file path to I/O error messages, making it easier
@@ -147,12 +304,11 @@ impl From<(std::io::Error, &PathBuf)> for AppPathError {
     }
 }
 
-/// Try to determine the executable directory (fallible version).
+/// Try to determine the executable file path (fallible version).
 ///
-/// This is the internal fallible initialization function that both the fallible
-/// and infallible APIs use. It handles all the edge cases properly without
-/// exposing them as errors to API users.
-pub(crate) fn try_exe_dir_init() -> Result<PathBuf, AppPathError> {
+/// Validates the result of [`std::env::current_exe()`] so callers can rely on a
+/// non-empty path.
+pub(crate) fn try_exe_file_init() -> Result<PathBuf, AppPathError> {
     let exe = current_exe().map_err(|e| {
         AppPathError::ExecutableNotFound(format!(
             "std::env::current_exe() failed: {e} (environment: {})",
@@ -167,6 +323,17 @@ pub(crate) fn try_exe_dir_init() -> Result<PathBuf, AppPathError> {
         )));
     }
 
+    Ok(exe)
+}
+
+/// Try to determine the executable directory (fallible version).
+///
+/// This is the internal fallible initialization function that both the fallible
+/// and infallible APIs use. It handles all the edge cases properly without
+/// exposing them as errors to API users.
+pub(crate) fn try_exe_dir_init() -> Result<PathBuf, AppPathError> {
+    let exe = crate::functions::try_exe_file()?;
+
     // Handle edge case: executable at filesystem root (jailed environments, etc.)
     // This is NOT an error - it's a valid case that should be handled internally
     let dir = match exe.parent() {
@@ -174,7 +341,7 @@ pub(crate) fn try_exe_dir_init() -> Result<PathBuf, AppPathError> {
         None => {
             // If exe has no parent (e.g., running as "/init" or "C:\myapp.exe"),
             // use the root directory itself
-            exe.ancestors().last().unwrap_or(&exe).to_path_buf()
+            exe.ancestors().last().unwrap_or(exe).to_path_buf()
         }
     };
 
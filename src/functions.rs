@@ -1,43 +1,42 @@
 use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+#[cfg(feature = "testing")]
+use std::sync::RwLock;
 
-use crate::error::{try_exe_dir_init, AppPathError};
+use crate::error::{try_exe_dir_init, try_exe_file_init, AppPathError};
 
 // Global executable directory - computed once, cached forever
 static EXE_DIR: OnceLock<PathBuf> = OnceLock::new();
 
-/// Get the executable's directory (fallible).
-///
-/// **Use this only for libraries or specialized applications.** Most applications should
-/// use [`crate::AppPath::try_new()`] for simpler, cleaner code.
-///
-/// # Examples
+// Global executable file path - computed once, cached forever
+static EXE_FILE: OnceLock<PathBuf> = OnceLock::new();
+
+// Install root baked in at build time, used when runtime detection fails
+#[cfg(feature = "compiled-base")]
+const COMPILED_BASE: Option<&str> = option_env!("APP_PATH_COMPILED_BASE");
+#[cfg(not(feature = "compiled-base"))]
+const COMPILED_BASE: Option<&str> = None;
+
+// Test-only replacement for the executable directory; takes precedence over `EXE_DIR`
+#[cfg(feature = "testing")]
+static EXE_DIR_OVERRIDE: RwLock<Option<&'static Path>> = RwLock::new(None);
+
+/// Get the application's base directory (fallible).
 ///
-/// ```rust
-/// use app_path::AppPath;
+/// Returns the same directory as [`crate::AppPath::try_new()`], as a plain `&'static Path`
+/// for code that does not need an `AppPath`. Most applications should use the
+/// `AppPath` constructors or [`exe_dir()`] instead.
 ///
-/// // Library with graceful error handling
-/// match AppPath::try_new() {
-///     Ok(app_base) => {
-///         println!("Application base directory: {}", app_base.display());
-///         let config = AppPath::with("config.toml");
-///     }
-///     Err(e) => {
-///         eprintln!("Failed to get application base directory: {e}");
-///         // Implement fallback strategy
-///     }
-/// }
+/// The directory is borrowed rather than returned as a `PathBuf` because it is cached
+/// for the life of the process; call `.to_path_buf()` when an owned copy is needed.
 ///
-/// // Use with ? operator for paths
-/// fn get_config_dir() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-///     let config = AppPath::try_with("config")?;
-///     Ok(config.into())
-/// }
-/// ```
+/// # Caching
 ///
 /// Once the executable directory is successfully determined by this function,
 /// the result is cached globally and all subsequent calls will use the cached value.
 /// This means that after the first successful call, `try_exe_dir()` will never return an error.
+/// The cache is shared with the `AppPath` constructors, and can be seeded from an
+/// environment variable before its first use with [`crate::AppPath::init_base_from_env()`].
 ///
 /// # Returns
 ///
@@ -54,6 +53,11 @@ static EXE_DIR: OnceLock<PathBuf> = OnceLock::new();
 /// After the first successful call, the executable directory is cached and this function
 /// will never return an error.
 ///
+/// With the `compiled-base` feature, an absolute directory in the `APP_PATH_COMPILED_BASE`
+/// environment variable **at build time** is used instead of returning these errors.
+/// This helps static musl binaries or containers where `current_exe()` is unreliable
+/// but the install root is known when building.
+///
 /// # Performance
 ///
 /// This function is highly optimized:
@@ -66,13 +70,12 @@ static EXE_DIR: OnceLock<PathBuf> = OnceLock::new();
 /// ## Library Error Handling
 ///
 /// ```rust
-/// use app_path::AppPath;
+/// use app_path::try_exe_dir;
 ///
 /// // Handle the error explicitly
-/// match AppPath::try_new() {
-///     Ok(app_base) => {
-///         println!("Application base directory: {}", app_base.display());
-///         // Use app_base for further operations
+/// match try_exe_dir() {
+///     Ok(base) => {
+///         println!("Application base directory: {}", base.display());
 ///     }
 ///     Err(e) => {
 ///         eprintln!("Failed to get application base directory: {e}");
@@ -84,22 +87,151 @@ static EXE_DIR: OnceLock<PathBuf> = OnceLock::new();
 /// ## Use with ? Operator
 ///
 /// ```rust
-/// use app_path::AppPath;
+/// use app_path::{try_exe_dir, AppPath};
 ///
-/// // Use with the ? operator in functions that return Result
-/// fn get_config_dir() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
-///     let config = AppPath::try_with("config")?;
-///     Ok(config.into())
+/// fn plugin_dir() -> Result<std::path::PathBuf, app_path::AppPathError> {
+///     Ok(try_exe_dir()?.join("plugins"))
 /// }
+///
+/// assert_eq!(plugin_dir()?, AppPath::with("plugins").into_path_buf());
+/// # Ok::<(), app_path::AppPathError>(())
 /// ```
 pub fn try_exe_dir() -> Result<&'static Path, AppPathError> {
+    #[cfg(feature = "testing")]
+    if let Some(dir) = *EXE_DIR_OVERRIDE.read().unwrap_or_else(|e| e.into_inner()) {
+        return Ok(dir);
+    }
+
     // If already cached, return it immediately
     if let Some(cached_path) = EXE_DIR.get() {
         return Ok(cached_path.as_path());
     }
 
     // Try to initialize and cache the result
-    let path = try_exe_dir_init()?;
+    let path = with_compiled_fallback(try_exe_dir_init(), COMPILED_BASE)?;
     let cached_path = EXE_DIR.get_or_init(|| path);
     Ok(cached_path.as_path())
 }
+
+/// Get the application's base directory.
+///
+/// The infallible counterpart of [`try_exe_dir()`], returning the same directory as
+/// [`crate::AppPath::new()`] without wrapping it. Shares the same global cache.
+///
+/// # Panics
+///
+/// Panics only if the application's base directory cannot be determined, which is extremely
+/// rare. After the first successful call, this function never panics.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{exe_dir, AppPath};
+///
+/// let base = exe_dir();
+/// assert_eq!(base, &*AppPath::new());
+/// assert!(base.is_absolute());
+/// ```
+pub fn exe_dir() -> &'static Path {
+    match try_exe_dir() {
+        Ok(dir) => dir,
+        Err(e) => panic!("Failed to determine application base directory: {e}"),
+    }
+}
+
+/// Get the running executable's file path (fallible), cached after the first success.
+///
+/// Shares the validation of [`try_exe_dir`], which derives the directory from this path.
+pub(crate) fn try_exe_file() -> Result<&'static Path, AppPathError> {
+    if let Some(cached_path) = EXE_FILE.get() {
+        return Ok(cached_path.as_path());
+    }
+
+    let path = try_exe_file_init()?;
+    let cached_path = EXE_FILE.get_or_init(|| path);
+    Ok(cached_path.as_path())
+}
+
+/// Seeds the cached base directory before its first use.
+///
+/// Returns `true` if `dir` is now the cached base: either nothing was cached yet, or
+/// the same directory already was. [`OnceLock::set`] makes this race-free against a
+/// concurrent first call to [`try_exe_dir`].
+pub(crate) fn seed_exe_dir(dir: PathBuf) -> bool {
+    match EXE_DIR.set(dir) {
+        Ok(()) => true,
+        Err(dir) => EXE_DIR.get() == Some(&dir),
+    }
+}
+
+/// Replaces the base directory with its canonical form, if it is not cached yet.
+///
+/// Returns `true` if the canonical directory is now the base, `false` if a different
+/// (non-canonical) directory was already cached. With the `testing` feature, an active
+/// override is canonicalized in place instead.
+pub(crate) fn canonicalize_exe_dir() -> Result<bool, AppPathError> {
+    #[cfg(feature = "testing")]
+    {
+        let mut guard = EXE_DIR_OVERRIDE.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(dir) = *guard {
+            let canonical = dir
+                .canonicalize()
+                .map_err(|e| AppPathError::from((e, &dir.to_path_buf())))?;
+            if canonical != dir {
+                *guard = Some(Box::leak(canonical.into_boxed_path()));
+            }
+            return Ok(true);
+        }
+    }
+
+    let dir = match EXE_DIR.get() {
+        Some(cached_path) => cached_path.clone(),
+        None => with_compiled_fallback(try_exe_dir_init(), COMPILED_BASE)?,
+    };
+    let canonical = dir
+        .canonicalize()
+        .map_err(|e| AppPathError::from((e, &dir)))?;
+    Ok(seed_exe_dir(canonical))
+}
+
+/// Installs a test-only base directory override.
+///
+/// The directory is leaked to satisfy the `&'static Path` contract of [`try_exe_dir`];
+/// this is acceptable because overrides are only set from tests.
+#[cfg(feature = "testing")]
+pub(crate) fn set_exe_dir_override(dir: PathBuf) {
+    let dir: &'static Path = Box::leak(dir.into_boxed_path());
+    *EXE_DIR_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = Some(dir);
+}
+
+/// Removes the test-only base directory override.
+#[cfg(feature = "testing")]
+pub(crate) fn clear_exe_dir_override() {
+    *EXE_DIR_OVERRIDE.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Replaces a detection failure with the compiled-in base, if one is usable.
+///
+/// Empty or relative values are ignored, since they cannot anchor a portable base.
+pub(crate) fn with_compiled_fallback(
+    detected: Result<PathBuf, AppPathError>,
+    compiled: Option<&str>,
+) -> Result<PathBuf, AppPathError> {
+    match (detected, compiled.map(Path::new)) {
+        (Err(_), Some(base)) if base.is_absolute() => Ok(base.to_path_buf()),
+        (detected, _) => detected,
+    }
+}
+
+/// Hashes bytes with 64-bit FNV-1a.
+///
+/// Used where a short identifier must stay stable across runs, Rust versions and
+/// platforms, which `std::collections::hash_map::DefaultHasher` does not guarantee.
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
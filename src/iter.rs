@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use crate::AppPath;
+
+/// Extension methods for iterators over [`AppPath`] values.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, AppPathIteratorExt};
+///
+/// let grouped = vec![
+///     AppPath::with("data/a.json"),
+///     AppPath::with("data/b.json"),
+///     AppPath::with("logs/app.log"),
+/// ]
+/// .into_iter()
+/// .group_by_parent();
+///
+/// assert_eq!(grouped[&AppPath::with("data")].len(), 2);
+/// assert_eq!(grouped[&AppPath::with("logs")].len(), 1);
+/// ```
+pub trait AppPathIteratorExt: Iterator<Item = AppPath> + Sized {
+    /// Buckets each path under its parent directory.
+    ///
+    /// Paths keep their iteration order within each bucket. A path without a parent
+    /// (a filesystem root) is grouped under itself.
+    fn group_by_parent(self) -> HashMap<AppPath, Vec<AppPath>> {
+        let mut groups: HashMap<AppPath, Vec<AppPath>> = HashMap::new();
+        for path in self {
+            let key = path.parent().unwrap_or_else(|| path.clone());
+            groups.entry(key).or_default().push(path);
+        }
+        groups
+    }
+}
+
+impl<I: Iterator<Item = AppPath>> AppPathIteratorExt for I {}
@@ -0,0 +1,25 @@
+/// What a layout entry passed to [`crate::AppPath::ensure_layout()`] represents.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, PathKind};
+///
+/// let created = AppPath::ensure_layout(&[
+///     (PathKind::Dir, "plugins"),
+///     (PathKind::File, "config/settings.toml"),
+/// ])?;
+/// assert!(created[0].is_dir());
+/// assert!(AppPath::with("config").is_dir());
+/// # std::fs::remove_dir_all(AppPath::with("plugins")).ok();
+/// # std::fs::remove_dir_all(AppPath::with("config")).ok();
+/// # Ok::<(), app_path::AppPathError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathKind {
+    /// A directory; it is created along with any missing parents.
+    Dir,
+
+    /// A file; only its parent directories are created, never the file itself.
+    File,
+}
@@ -47,6 +47,8 @@
 //! - [`AppPath::try_with_override()`] - **Deployment (Fallible)**: Fallible environment-configurable paths
 //! - [`AppPath::with_override_fn()`] - **Advanced**: Function-based override logic
 //! - [`AppPath::try_with_override_fn()`] - **Advanced (Fallible)**: Fallible function-based override logic
+//! - [`AppPath::with_override_envs()`] - **Deployment**: First non-empty of several environment variables
+//! - [`AppPath::builder()`] - **Composition**: Combine a default, ordered overrides, base choice and portability in one call
 //!
 //! ### Directory Creation
 //!
@@ -141,6 +143,9 @@
 //!
 //! ### UTF-8 Path Serialization (camino)
 //!
+//! With the `camino` feature, `AppPath::to_utf8()` and `AppPath::try_into_utf8()` do
+//! this conversion in one call. Without it, convert manually:
+//!
 //! ```rust
 //! use app_path::app_path;
 //! use camino::Utf8PathBuf;
@@ -153,13 +158,19 @@
 //!
 //! ### Cross-Platform Path Types (typed-path)
 //!
+//! With the `typed-path` feature, `AppPath::to_typed_native()`, `AppPath::to_typed_windows()`
+//! and `AppPath::to_typed_unix()` do this conversion in one call. Without it, convert
+//! the path's text rather than [`AppPath::to_bytes()`], whose encoding is platform-specific:
+//!
 //! ```rust
 //! use app_path::app_path;
-//! use typed_path::{WindowsPath, UnixPath};
+//! use typed_path::{NativePathBuf, UnixEncoding, WindowsEncoding};
 //!
 //! let dist_dir = app_path!("dist");
-//! let win_path = WindowsPath::new(&dist_dir.to_bytes());
-//! let unix_path = UnixPath::new(&dist_dir.to_bytes());
+//! let text = dist_dir.to_string_lossy();
+//! let native = NativePathBuf::from(&*text);
+//! let win_path = native.with_encoding::<WindowsEncoding>();
+//! let unix_path = native.with_encoding::<UnixEncoding>();
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
@@ -177,18 +188,40 @@
 //! variant [`AppPath::try_new()`] instead.
 
 mod app_path;
+#[cfg(feature = "cache")]
+mod cache;
+mod deployment;
+mod env_overrides;
 mod error;
 mod functions;
+mod iter;
+mod layout;
+mod matcher;
+mod policy;
+mod resolution;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export the public API
-pub use app_path::AppPath;
+pub use app_path::{AppPath, AppPathBuilder, AppPathShared, RelativeAppPath};
+#[cfg(feature = "camino")]
+pub use camino::Utf8PathBuf;
+pub use deployment::DeploymentMode;
 pub use error::AppPathError;
+pub use functions::{exe_dir, try_exe_dir};
+pub use iter::AppPathIteratorExt;
+pub use layout::PathKind;
+pub use matcher::PathMatcher;
+#[cfg(feature = "mmap")]
+pub use memmap2::Mmap;
+pub use policy::AbsolutePolicy;
+pub use resolution::ResolutionSource;
+#[cfg(feature = "typed-path")]
+pub use typed_path::{NativePathBuf, UnixPathBuf, WindowsPathBuf};
 
-// Internal functions for tests and crate internals
-pub(crate) use functions::try_exe_dir;
+#[doc(hidden)]
+pub use env_overrides::__env_override;
 
 /// Convenience macro for creating `AppPath` instances with optional environment variable overrides.
 ///
@@ -197,8 +230,11 @@ pub(crate) use functions::try_exe_dir;
 /// - `app_path!()` - Application base directory (equivalent to `AppPath::new()`)
 /// - `app_path!(path)` - Simple path creation (equivalent to `AppPath::with(path)`)
 /// - `app_path!(path, env = "VAR_NAME")` - With environment variable override
+/// - `app_path!(path, envs = ["A", "B"])` - First non-empty environment variable wins
 /// - `app_path!(path, override = expression)` - With optional override expression
 /// - `app_path!(path, fn = function)` - With function-based override logic
+/// - `app_path!(base = dir, path)` - Relative to a custom base directory (`AppPath::with_base`)
+/// - `app_path![seg1, seg2, ...]` - Joins several segments onto the base directory in one call
 ///
 /// # Examples
 ///
@@ -208,17 +244,26 @@ pub(crate) use functions::try_exe_dir;
 /// let config = app_path!("config.toml");
 /// let data_dir = app_path!("data", env = "DATA_DIR");
 /// let log_file = app_path!("app.log", override = std::env::args().nth(1));
+///
+/// let id = 42;
+/// let user_log = app_path!["data", "2024", format!("user-{id}.log")];
 /// ```
 #[macro_export]
 macro_rules! app_path {
     () => {
         $crate::AppPath::new()
     };
+    (base = $base:expr, $path:expr) => {
+        $crate::AppPath::with_base($base, $path)
+    };
     ($path:expr) => {
         $crate::AppPath::with($path)
     };
     ($path:expr, env = $env_var:expr) => {
-        $crate::AppPath::with_override($path, ::std::env::var($env_var).ok())
+        $crate::AppPath::with_override($path, $crate::__env_override($env_var))
+    };
+    ($path:expr, envs = [$($env_var:expr),* $(,)?]) => {
+        $crate::AppPath::with_override_envs($path, &[$($env_var),*])
     };
     ($path:expr, override = $override_expr:expr) => {
         $crate::AppPath::with_override($path, $override_expr)
@@ -226,6 +271,14 @@ macro_rules! app_path {
     ($path:expr, fn = $override_fn:expr) => {
         $crate::AppPath::with_override_fn($path, $override_fn)
     };
+    ($first:expr, $($segment:expr),+ $(,)?) => {
+        $crate::AppPath::with({
+            let mut path = ::std::path::PathBuf::new();
+            path.push($first);
+            $(path.push($segment);)+
+            path
+        })
+    };
 }
 
 /// Fallible version of [`app_path!`] that returns a [`Result`] instead of panicking.
@@ -239,8 +292,11 @@ macro_rules! app_path {
 /// - `try_app_path!()` - Application base directory (equivalent to `AppPath::try_new()`)
 /// - `try_app_path!(path)` - Simple path creation (equivalent to `AppPath::try_with(path)`)
 /// - `try_app_path!(path, env = "VAR_NAME")` - With environment variable override
+/// - `try_app_path!(path, envs = ["A", "B"])` - First non-empty environment variable wins
 /// - `try_app_path!(path, override = expression)` - With any optional override expression
 /// - `try_app_path!(path, fn = function)` - With function-based override logic
+/// - `try_app_path!(base = dir, path)` - Relative to a custom base directory (`AppPath::try_with_base`)
+/// - `try_app_path![seg1, seg2, ...]` - Joins several segments onto the base directory in one call
 ///
 /// # Examples
 ///
@@ -306,6 +362,7 @@ macro_rules! app_path {
 ///             _ => eprintln!("Other I/O error"),
 ///         }
 ///     }
+///     Err(e) => eprintln!("Failed to resolve path: {e}"),
 /// }
 /// ```
 ///
@@ -347,11 +404,17 @@ macro_rules! try_app_path {
     () => {
         $crate::AppPath::try_new()
     };
+    (base = $base:expr, $path:expr) => {
+        $crate::AppPath::try_with_base($base, $path)
+    };
     ($path:expr) => {
         $crate::AppPath::try_with($path)
     };
     ($path:expr, env = $env_var:expr) => {
-        $crate::AppPath::try_with_override($path, ::std::env::var($env_var).ok())
+        $crate::AppPath::try_with_override($path, $crate::__env_override($env_var))
+    };
+    ($path:expr, envs = [$($env_var:expr),* $(,)?]) => {
+        $crate::AppPath::try_with_override_envs($path, &[$($env_var),*])
     };
     ($path:expr, override = $override_expr:expr) => {
         $crate::AppPath::try_with_override($path, $override_expr)
@@ -359,4 +422,12 @@ macro_rules! try_app_path {
     ($path:expr, fn = $override_fn:expr) => {
         $crate::AppPath::try_with_override_fn($path, $override_fn)
     };
+    ($first:expr, $($segment:expr),+ $(,)?) => {
+        $crate::AppPath::try_with({
+            let mut path = ::std::path::PathBuf::new();
+            path.push($first);
+            $(path.push($segment);)+
+            path
+        })
+    };
 }
@@ -0,0 +1,151 @@
+use crate::AppPath;
+
+/// A lightweight glob-style matcher for base-relative paths.
+///
+/// Patterns are matched against paths relative to the application's base directory,
+/// always using `/` as the separator regardless of platform. Supported syntax:
+///
+/// | Pattern | Matches |
+/// |---------|---------|
+/// | `*`     | Any run of characters within one path component (including none) |
+/// | `?`     | Exactly one character within one path component |
+/// | `**`    | Any number of whole path components (including none); must be a component on its own |
+///
+/// Every other character matches itself literally and matching is case-sensitive.
+/// There are no character classes, alternation or escapes. A leading `/` or empty
+/// components in the pattern are ignored.
+///
+/// Paths outside the base directory never match.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, PathMatcher};
+///
+/// let configs = PathMatcher::new("data/*/config.*");
+/// assert!(configs.matches(&AppPath::with("data/plugin-a/config.toml")));
+/// assert!(!configs.matches(&AppPath::with("data/a/b/config.toml")));
+///
+/// let any_json = PathMatcher::new("**/*.json");
+/// assert!(any_json.matches(&AppPath::with("users.json")));
+/// assert!(any_json.matches(&AppPath::with("data/users/1.json")));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PathMatcher {
+    segments: Vec<String>,
+}
+
+impl PathMatcher {
+    /// Compiles a pattern. See the [type documentation](PathMatcher) for the syntax.
+    ///
+    /// Consecutive `**` components are collapsed into one, since they match the same
+    /// paths.
+    pub fn new(pattern: &str) -> Self {
+        let mut segments: Vec<String> = pattern
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .collect();
+        segments.dedup_by(|next, prev| next == "**" && prev == "**");
+        Self { segments }
+    }
+
+    /// Returns `true` if `path`, taken relative to the application's base directory,
+    /// matches the pattern.
+    ///
+    /// `.` and `..` in the path are resolved lexically first. The check never touches
+    /// the filesystem.
+    pub fn matches(&self, path: &AppPath) -> bool {
+        match path.normalized_base_components() {
+            Some(components) => {
+                let components: Vec<String> = components
+                    .iter()
+                    .map(|component| component.to_string_lossy().into_owned())
+                    .collect();
+                self.matches_components(&components)
+            }
+            None => false,
+        }
+    }
+
+    /// Matches already-split relative path components.
+    pub(crate) fn matches_components<S: AsRef<str>>(&self, components: &[S]) -> bool {
+        match_segments(&self.segments, components)
+    }
+}
+
+/// Matches pattern segments against path components in `O(segments * components)`.
+///
+/// Tracks every pattern position the components consumed so far can end at, instead
+/// of backtracking over each way a `**` could split the path.
+fn match_segments<S: AsRef<str>>(segments: &[String], components: &[S]) -> bool {
+    // reached[i]: the components so far are matched by the first `i` segments
+    let mut reached = vec![false; segments.len() + 1];
+    reached[0] = true;
+    skip_globstars(segments, &mut reached);
+
+    for component in components {
+        let mut next = vec![false; segments.len() + 1];
+        for (i, segment) in segments.iter().enumerate() {
+            if !reached[i] {
+                continue;
+            }
+            if segment == "**" {
+                // `**` absorbs the component and stays available for the next one
+                next[i] = true;
+            } else if match_component(segment, component.as_ref()) {
+                next[i + 1] = true;
+            }
+        }
+        skip_globstars(segments, &mut next);
+        if !next.contains(&true) {
+            return false;
+        }
+        reached = next;
+    }
+
+    reached[segments.len()]
+}
+
+/// Lets every reached `**` also match no components at all.
+fn skip_globstars(segments: &[String], reached: &mut [bool]) {
+    for (i, segment) in segments.iter().enumerate() {
+        if reached[i] && segment == "**" {
+            reached[i + 1] = true;
+        }
+    }
+}
+
+/// Matches `*` and `?` wildcards within a single component.
+fn match_component(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text index it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` absorb one more character and retry
+                Some((star, star_t)) => {
+                    p = star + 1;
+                    t = star_t + 1;
+                    backtrack = Some((star, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,92 @@
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+use crate::AppPathError;
+
+/// Process-wide policy for absolute path inputs to `AppPath` constructors.
+///
+/// Absolute inputs bypass the application's base directory, which defeats portability.
+/// Setting a policy with [`crate::AppPath::set_absolute_policy()`] enforces the
+/// portability invariant for a whole application in one place.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AbsolutePolicy, AppPath};
+///
+/// assert_eq!(AppPath::absolute_policy(), AbsolutePolicy::Allow);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AbsolutePolicy {
+    /// Absolute inputs are used as-is (the default behavior).
+    #[default]
+    Allow,
+
+    /// Absolute inputs are rejected with [`AppPathError::AbsolutePathRejected`].
+    Reject,
+
+    /// Absolute inputs are used as-is, but reported to the absolute-path observer.
+    ///
+    /// See [`crate::AppPath::set_absolute_observer()`]. The library never prints, so
+    /// without an observer installed absolute inputs are allowed silently.
+    Warn,
+}
+
+impl AbsolutePolicy {
+    const fn to_u8(self) -> u8 {
+        match self {
+            AbsolutePolicy::Allow => 0,
+            AbsolutePolicy::Reject => 1,
+            AbsolutePolicy::Warn => 2,
+        }
+    }
+
+    const fn from_u8(value: u8) -> Self {
+        match value {
+            1 => AbsolutePolicy::Reject,
+            2 => AbsolutePolicy::Warn,
+            _ => AbsolutePolicy::Allow,
+        }
+    }
+}
+
+// Global absolute-path policy and observer - set once at startup, read by every constructor
+static ABSOLUTE_POLICY: AtomicU8 = AtomicU8::new(AbsolutePolicy::Allow.to_u8());
+static ABSOLUTE_OBSERVER: RwLock<Option<fn(&Path)>> = RwLock::new(None);
+
+pub(crate) fn absolute_policy() -> AbsolutePolicy {
+    AbsolutePolicy::from_u8(ABSOLUTE_POLICY.load(Ordering::Acquire))
+}
+
+pub(crate) fn set_absolute_policy(policy: AbsolutePolicy) {
+    ABSOLUTE_POLICY.store(policy.to_u8(), Ordering::Release);
+}
+
+pub(crate) fn set_absolute_observer(observer: Option<fn(&Path)>) {
+    // A poisoned lock only means an observer panicked; the stored value is still valid
+    *ABSOLUTE_OBSERVER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = observer;
+}
+
+/// Applies the current [`AbsolutePolicy`] to a constructor input.
+pub(crate) fn check_absolute_input(path: &Path) -> Result<(), AppPathError> {
+    if !path.is_absolute() {
+        return Ok(());
+    }
+
+    match absolute_policy() {
+        AbsolutePolicy::Allow => Ok(()),
+        AbsolutePolicy::Reject => Err(AppPathError::AbsolutePathRejected(path.to_path_buf())),
+        AbsolutePolicy::Warn => {
+            let observer = *ABSOLUTE_OBSERVER
+                .read()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some(observer) = observer {
+                observer(path);
+            }
+            Ok(())
+        }
+    }
+}
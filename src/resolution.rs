@@ -0,0 +1,30 @@
+/// Where a resolved path came from.
+///
+/// Returned by [`crate::AppPath::resolve_reported()`] and the `*_traced` constructors
+/// such as [`crate::AppPath::with_override_traced()`], so applications can show exactly
+/// where each file is read from and why, e.g. in a startup banner.
+///
+/// # Examples
+///
+/// ```rust
+/// use app_path::{AppPath, ResolutionSource};
+///
+/// for (path, source) in AppPath::resolve_reported(&[("config.toml", "MYAPP_CONFIG")]) {
+///     match source {
+///         ResolutionSource::Default => println!("{path} (default)"),
+///         ResolutionSource::Override => println!("{path} (override)"),
+///         ResolutionSource::EnvOverride(var) => println!("{path} (from ${var})"),
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResolutionSource {
+    /// The default path was used because no override applied.
+    Default,
+
+    /// An explicitly passed override value was used.
+    Override,
+
+    /// The named environment variable was set and its value was used.
+    EnvOverride(String),
+}
@@ -0,0 +1,35 @@
+use crate::AppPath;
+use std::env;
+
+#[tokio::test]
+async fn test_with_override_async_some() {
+    let override_dir = env::temp_dir().join("app_path_test_async_override");
+    let expected = override_dir.clone();
+
+    let path = AppPath::with_override_async("data", || async move { Some(override_dir) })
+        .await
+        .unwrap();
+
+    assert_eq!(path, AppPath::with(expected));
+}
+
+#[tokio::test]
+async fn test_with_override_async_none() {
+    let path = AppPath::with_override_async("data", || async { None::<String> })
+        .await
+        .unwrap();
+
+    assert_eq!(path, AppPath::with("data"));
+}
+
+#[tokio::test]
+async fn test_with_override_async_awaits_before_resolving() {
+    let path = AppPath::with_override_async("data", || async {
+        tokio::task::yield_now().await;
+        Some("remote/data")
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(path, AppPath::with("remote/data"));
+}
@@ -0,0 +1,377 @@
+use crate::{AppPath, AppPathError};
+use std::path::{Path, PathBuf};
+
+#[test]
+fn test_relative_component_count_base() {
+    assert_eq!(AppPath::new().relative_component_count(), Some(0));
+    assert_eq!(AppPath::with("").relative_component_count(), Some(0));
+}
+
+#[test]
+fn test_relative_component_count_direct_child() {
+    assert_eq!(
+        AppPath::with("config.toml").relative_component_count(),
+        Some(1)
+    );
+    assert_eq!(AppPath::with("./data").relative_component_count(), Some(1));
+}
+
+#[test]
+fn test_relative_component_count_nested() {
+    assert_eq!(
+        AppPath::with("data/users/2024/profile.json").relative_component_count(),
+        Some(4)
+    );
+    assert_eq!(
+        AppPath::with("data/../logs/app.log").relative_component_count(),
+        Some(2)
+    );
+}
+
+#[test]
+fn test_relative_component_count_out_of_base() {
+    let outside = if cfg!(windows) {
+        r"C:\temp\outside.txt"
+    } else {
+        "/tmp/outside.txt"
+    };
+
+    assert_eq!(AppPath::with(outside).relative_component_count(), None);
+    assert_eq!(
+        AppPath::with("../escape.txt").relative_component_count(),
+        None
+    );
+    assert_eq!(
+        AppPath::with("data/../../escape.txt").relative_component_count(),
+        None
+    );
+}
+
+#[test]
+fn test_storage_key_in_base() {
+    assert_eq!(
+        AppPath::with("config.toml").storage_key(),
+        "rel_config.toml"
+    );
+    assert_eq!(
+        AppPath::with("data/users/1.json").storage_key(),
+        "rel_data%2Fusers%2F1.json"
+    );
+    assert_eq!(
+        AppPath::with("cache/photo 1 (copy).png").storage_key(),
+        "rel_cache%2Fphoto%201%20%28copy%29.png"
+    );
+}
+
+#[test]
+fn test_storage_key_is_stable() {
+    let key = AppPath::with("data/users.db").storage_key();
+
+    assert_eq!(key, AppPath::with("data/users.db").storage_key());
+    // Lexically equivalent spellings share a key
+    assert_eq!(key, AppPath::with("./data/users.db").storage_key());
+    assert_eq!(key, AppPath::with("data/tmp/../users.db").storage_key());
+}
+
+#[test]
+fn test_storage_key_out_of_base() {
+    let outside = std::env::temp_dir().join("storage_key_outside.db");
+    let key = AppPath::with(&outside).storage_key();
+
+    assert!(key.starts_with("abs_"));
+    assert_eq!(key.len(), "abs_".len() + 16);
+    assert_eq!(key, AppPath::with(&outside).storage_key());
+    assert_ne!(
+        key,
+        AppPath::with(std::env::temp_dir().join("other.db")).storage_key()
+    );
+
+    // Escaping the base with `..` is also out-of-base
+    assert!(AppPath::with("../escape.db")
+        .storage_key()
+        .starts_with("abs_"));
+}
+
+#[test]
+fn test_storage_key_has_no_separators() {
+    let paths = [
+        AppPath::with("a/b/c/d.txt"),
+        AppPath::with("dir\\with\\backslashes.txt"),
+        AppPath::with("../outside/file.txt"),
+        AppPath::new(),
+    ];
+
+    for path in paths {
+        let key = path.storage_key();
+        assert!(!key.contains('/'), "{key}");
+        assert!(!key.contains('\\'), "{key}");
+        assert!(key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-._~%".contains(c)));
+    }
+}
+
+#[test]
+fn test_relative_display_with_root_under_root() {
+    let data = AppPath::with("data");
+    let user = AppPath::with("data/users/1.json");
+
+    assert_eq!(
+        user.relative_display_with_root(&data, "data"),
+        Some("[data] users/1.json".to_string())
+    );
+    assert_eq!(
+        AppPath::with("data/readme.txt").relative_display_with_root(&data, "store"),
+        Some("[store] readme.txt".to_string())
+    );
+    assert_eq!(
+        data.relative_display_with_root(&data, "data"),
+        Some("[data] .".to_string())
+    );
+}
+
+#[test]
+fn test_relative_display_with_root_not_under_root() {
+    let plugins = AppPath::with("plugins");
+
+    assert_eq!(
+        AppPath::with("data/users/1.json").relative_display_with_root(&plugins, "plugins"),
+        None
+    );
+    // Matches whole components only, not string prefixes
+    assert_eq!(
+        AppPath::with("plugins-old/a.dll").relative_display_with_root(&plugins, "plugins"),
+        None
+    );
+    assert_eq!(
+        AppPath::new().relative_display_with_root(&plugins, "plugins"),
+        None
+    );
+}
+
+#[test]
+fn test_require_relative_to_contained() {
+    let data = AppPath::with("data");
+
+    assert_eq!(
+        AppPath::with("data/users/1.json")
+            .require_relative_to(&data)
+            .unwrap(),
+        Path::new("users/1.json")
+    );
+    assert_eq!(
+        AppPath::with("data/a/../b.txt")
+            .require_relative_to(&data)
+            .unwrap(),
+        Path::new("b.txt")
+    );
+    assert_eq!(data.require_relative_to(&data).unwrap(), PathBuf::new());
+}
+
+#[test]
+fn test_require_relative_to_not_contained() {
+    let data = AppPath::with("data");
+
+    for path in ["logs/app.log", "data/../secrets.txt", "data-old/file.txt"] {
+        let candidate = AppPath::with(path);
+        match candidate.require_relative_to(&data) {
+            Err(AppPathError::PathEscapesBase { path, base }) => {
+                assert_eq!(path, candidate.to_path_buf());
+                assert_eq!(base, data.to_path_buf());
+            }
+            other => panic!("Expected PathEscapesBase for {path}, got: {other:?}"),
+        }
+    }
+
+    let err = AppPath::with("logs/app.log")
+        .require_relative_to(&data)
+        .unwrap_err();
+    assert!(err.to_string().contains("escapes base"));
+}
+
+#[test]
+fn test_is_descendant_of_genuine() {
+    let data = AppPath::with("data");
+
+    assert!(AppPath::with("data/users").is_descendant_of(&data));
+    assert!(AppPath::with("data/users/2024/1.json").is_descendant_of(&data));
+    assert!(AppPath::with("data/./users/../cache").is_descendant_of(&data));
+    assert!(data.is_ancestor_of(&AppPath::with("data/users")));
+    assert!(AppPath::new().is_ancestor_of(&data));
+}
+
+#[test]
+fn test_is_descendant_of_same_path_is_not_strict() {
+    let data = AppPath::with("data");
+
+    assert!(!data.is_descendant_of(&data));
+    assert!(!AppPath::with("data/").is_descendant_of(&data));
+    assert!(!AppPath::with("data/users/..").is_descendant_of(&data));
+    assert!(!data.is_ancestor_of(&data));
+}
+
+#[test]
+fn test_is_descendant_of_siblings() {
+    let data = AppPath::with("data");
+
+    assert!(!AppPath::with("logs/app.log").is_descendant_of(&data));
+    assert!(!AppPath::with("data-old/file").is_descendant_of(&data));
+    assert!(!AppPath::with("dat").is_descendant_of(&data));
+    assert!(!data.is_ancestor_of(&AppPath::with("database.db")));
+}
+
+#[test]
+fn test_is_descendant_of_escaping_inputs() {
+    let data = AppPath::with("data");
+
+    // Raw `starts_with` would accept these
+    assert!(AppPath::with("data/../secrets.txt").starts_with(&data));
+    assert!(!AppPath::with("data/../secrets.txt").is_descendant_of(&data));
+    assert!(!AppPath::with("data/users/../../etc/passwd").is_descendant_of(&data));
+
+    // A normalized ancestor still works
+    let sneaky_ancestor = AppPath::with("other/../data");
+    assert!(AppPath::with("data/file.txt").is_descendant_of(&sneaky_ancestor));
+
+    // Child is the parent's descendant, not the other way around
+    let child = AppPath::with("data/users");
+    assert!(!data.is_descendant_of(&child));
+}
+
+#[test]
+fn test_is_within_base_contained() {
+    assert!(AppPath::new().is_within_base());
+    assert!(AppPath::with("config.toml").is_within_base());
+    assert!(AppPath::with("plugins/audio/lib.so").is_within_base());
+    assert!(AppPath::with("plugins/../data/./file.txt").is_within_base());
+
+    // Not-yet-existing paths work since the filesystem is never consulted
+    assert!(AppPath::with("does/not/exist/yet.txt").is_within_base());
+}
+
+#[test]
+fn test_is_within_base_parent_escapes() {
+    assert!(!AppPath::with("..").is_within_base());
+    assert!(!AppPath::with("../../etc/passwd").is_within_base());
+    assert!(!AppPath::with("plugins/../../outside.txt").is_within_base());
+}
+
+#[test]
+fn test_is_within_base_absolute_outside() {
+    let outside = std::env::temp_dir().join("app_path_within_base_outside.txt");
+    assert!(!AppPath::with(&outside).is_within_base());
+}
+
+#[test]
+fn test_is_within_base_sibling_prefix() {
+    let base = AppPath::new();
+    let name = base.file_name().unwrap().to_string_lossy().into_owned();
+
+    // `<exe_dir>_backup` shares a string prefix with the base but is a sibling
+    let backup = AppPath::with(format!("../{name}_backup/plugin.so"));
+    assert!(backup
+        .to_string_lossy()
+        .starts_with(&*base.to_string_lossy()));
+    assert!(!backup.is_within_base());
+
+    let absolute_backup = AppPath::with(PathBuf::from(format!("{}_backup", base.display())));
+    assert!(!absolute_backup.is_within_base());
+
+    // Going out and back in is still contained
+    let round_trip = AppPath::with(format!("../{name}/plugin.so"));
+    assert!(round_trip.is_within_base());
+}
+
+#[test]
+fn test_relative_to_base_from_relative_input() {
+    assert_eq!(
+        AppPath::with("data/users.db").relative_to_base(),
+        Some(Path::new("data/users.db"))
+    );
+    assert_eq!(AppPath::new().relative_to_base(), Some(Path::new("")));
+
+    // Not normalized: components are kept as written
+    assert_eq!(
+        AppPath::with("data/../logs").relative_to_base(),
+        Some(Path::new("data/../logs"))
+    );
+}
+
+#[test]
+fn test_relative_to_base_override_inside_base() {
+    let inside = AppPath::new().join("plugins/audio.so");
+    let overridden = AppPath::with_override("default.so", Some(&inside));
+    assert_eq!(
+        overridden.relative_to_base(),
+        Some(Path::new("plugins/audio.so"))
+    );
+}
+
+#[test]
+fn test_relative_to_base_override_outside_base() {
+    let outside = PathBuf::from(format!("{}_elsewhere", AppPath::new().display())).join("users.db");
+    let overridden = AppPath::with_override("data/users.db", Some(&outside));
+    assert_eq!(overridden.relative_to_base(), None);
+}
+
+#[test]
+fn test_components_relative_two_levels() {
+    use std::ffi::OsStr;
+    use std::path::Component;
+
+    let path = AppPath::with("data/users.db");
+    let components: Vec<_> = path.components_relative().collect();
+    assert_eq!(
+        components,
+        [
+            Component::Normal(OsStr::new("data")),
+            Component::Normal(OsStr::new("users.db"))
+        ]
+    );
+}
+
+#[test]
+fn test_components_relative_at_base_is_empty() {
+    assert_eq!(AppPath::new().components_relative().count(), 0);
+}
+
+#[test]
+fn test_components_relative_out_of_base_override() {
+    let outside = PathBuf::from(format!("{}_elsewhere", AppPath::new().display())).join("users.db");
+    let overridden = AppPath::with_override("data/users.db", Some(&outside));
+    assert_eq!(overridden.components_relative().count(), 0);
+}
+
+#[test]
+fn test_display_relative_contained() {
+    let db = AppPath::with("data/users.db");
+    assert_eq!(
+        Path::new(&db.display_relative()),
+        Path::new("data/users.db")
+    );
+    assert_eq!(AppPath::new().display_relative(), ".");
+
+    // Display itself stays absolute
+    assert_eq!(db.to_string(), db.display().to_string());
+    assert_ne!(db.to_string(), db.display_relative());
+}
+
+#[test]
+fn test_display_relative_override_inside_base() {
+    let inside = AppPath::new().join("plugins/audio.so");
+    let overridden = AppPath::with_override("default.so", Some(&inside));
+    assert_eq!(
+        Path::new(&overridden.display_relative()),
+        Path::new("plugins/audio.so")
+    );
+}
+
+#[test]
+fn test_display_relative_outside_base_is_absolute() {
+    let outside = PathBuf::from(format!("{}_elsewhere", AppPath::new().display())).join("users.db");
+    let overridden = AppPath::with_override("data/users.db", Some(&outside));
+    assert_eq!(overridden.display_relative(), outside.display().to_string());
+
+    let escaped = AppPath::with("../shared/users.db");
+    assert_eq!(escaped.display_relative(), escaped.display().to_string());
+}
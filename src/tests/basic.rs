@@ -150,3 +150,64 @@ fn test_exe_dir_function() {
     let expected = dir.join("test.txt");
     assert_eq!(&*config, expected.as_path());
 }
+
+#[test]
+fn test_exe_dir_functions_match_constructors() {
+    let base = crate::exe_dir();
+    assert_eq!(base, &*AppPath::new());
+    assert_eq!(crate::try_exe_dir().unwrap(), base);
+    assert_eq!(
+        base,
+        env::current_exe().unwrap().parent().unwrap(),
+        "base directory should be the test executable's directory"
+    );
+
+    // Both return the same cached directory
+    assert!(std::ptr::eq(crate::exe_dir(), crate::exe_dir()));
+}
+
+#[test]
+fn test_use_canonical_base() {
+    super::support::run_isolated("tests::basic::isolated_use_canonical_base");
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_use_canonical_base"]
+fn isolated_use_canonical_base() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let canonical = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .canonicalize()
+        .unwrap();
+
+    assert!(AppPath::use_canonical_base().unwrap());
+    assert!(AppPath::use_canonical_base().unwrap());
+    assert_eq!(&*AppPath::new(), canonical.as_path());
+    assert_eq!(
+        &*AppPath::with("config.toml"),
+        canonical.join("config.toml")
+    );
+}
+
+#[test]
+fn test_use_canonical_base_after_first_use() {
+    super::support::run_isolated("tests::basic::isolated_use_canonical_base_after_first_use");
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_use_canonical_base_after_first_use"]
+fn isolated_use_canonical_base_after_first_use() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let base = AppPath::new();
+
+    // Only a base that is already canonical can be kept once paths were resolved
+    let canonical = base.canonicalize().unwrap();
+    assert_eq!(AppPath::use_canonical_base().unwrap(), base == canonical);
+    assert_eq!(AppPath::new(), base);
+}
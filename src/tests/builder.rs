@@ -0,0 +1,255 @@
+use crate::{AppPath, AppPathError};
+use std::env;
+
+#[test]
+fn test_builder_without_options_matches_try_with() {
+    let built = AppPath::builder()
+        .default("config.toml")
+        .try_build()
+        .unwrap();
+    assert_eq!(built, AppPath::try_with("config.toml").unwrap());
+
+    // No default: the base directory itself
+    assert_eq!(AppPath::builder().build(), AppPath::new());
+}
+
+#[test]
+fn test_builder_env_override() {
+    let custom_path = env::temp_dir().join("builder_env_override.toml");
+    env::set_var("BUILDER_TEST_ENV", &custom_path);
+
+    let built = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_ENV")
+        .build();
+    assert_eq!(&*built, custom_path.as_path());
+
+    env::remove_var("BUILDER_TEST_ENV");
+}
+
+#[test]
+fn test_builder_env_priority_order() {
+    env::set_var("BUILDER_TEST_SECOND", "second.toml");
+
+    // First variable is unset, so the second one wins
+    let built = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_FIRST_UNSET")
+        .env("BUILDER_TEST_SECOND")
+        .build();
+    assert_eq!(built, AppPath::with("second.toml"));
+
+    // No variable set - falls back to the default
+    let fallback = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_FIRST_UNSET")
+        .build();
+    assert_eq!(fallback, AppPath::with("config.toml"));
+
+    env::remove_var("BUILDER_TEST_SECOND");
+}
+
+#[test]
+fn test_builder_or_fn() {
+    let built = AppPath::builder()
+        .default("config.toml")
+        .or_fn(|| Some("from_fn.toml"))
+        .build();
+    assert_eq!(built, AppPath::with("from_fn.toml"));
+
+    let fallback = AppPath::builder()
+        .default("config.toml")
+        .or_fn(|| None::<String>)
+        .build();
+    assert_eq!(fallback, AppPath::with("config.toml"));
+}
+
+#[test]
+fn test_builder_conflicting_env_and_fn_follow_declaration_order() {
+    env::set_var("BUILDER_TEST_CONFLICT", "from_env.toml");
+
+    let env_first = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_CONFLICT")
+        .or_fn(|| Some("from_fn.toml"))
+        .build();
+    assert_eq!(env_first, AppPath::with("from_env.toml"));
+
+    let fn_first = AppPath::builder()
+        .default("config.toml")
+        .or_fn(|| Some("from_fn.toml"))
+        .env("BUILDER_TEST_CONFLICT")
+        .build();
+    assert_eq!(fn_first, AppPath::with("from_fn.toml"));
+
+    // Later overrides are not consulted once one applies
+    let later_fn_skipped = AppPath::builder()
+        .env("BUILDER_TEST_CONFLICT")
+        .or_fn(|| -> Option<&str> { panic!("must not be called") })
+        .build();
+    assert_eq!(later_fn_skipped, AppPath::with("from_env.toml"));
+
+    env::remove_var("BUILDER_TEST_CONFLICT");
+}
+
+#[test]
+fn test_builder_base() {
+    let base = env::temp_dir().join("builder_plugin_root");
+
+    let built = AppPath::builder()
+        .base(&base)
+        .default("settings.toml")
+        .build();
+    assert_eq!(&*built, base.join("settings.toml").as_path());
+
+    // Overrides are joined onto the chosen base too
+    let overridden = AppPath::builder()
+        .base(&base)
+        .default("settings.toml")
+        .or_fn(|| Some("custom.toml"))
+        .build();
+    assert_eq!(&*overridden, base.join("custom.toml").as_path());
+
+    // Absolute paths still replace the base
+    let absolute = env::temp_dir().join("builder_absolute.toml");
+    let replaced = AppPath::builder().base(&base).default(&absolute).build();
+    assert_eq!(&*replaced, absolute.as_path());
+}
+
+#[test]
+fn test_builder_cwd_relative() {
+    let built = AppPath::builder()
+        .default("data/input.csv")
+        .cwd_relative(true)
+        .build();
+    assert_eq!(
+        &*built,
+        env::current_dir().unwrap().join("data/input.csv").as_path()
+    );
+
+    let exe_relative = AppPath::builder()
+        .default("data/input.csv")
+        .cwd_relative(false)
+        .build();
+    assert_eq!(exe_relative, AppPath::with("data/input.csv"));
+}
+
+#[test]
+fn test_builder_last_base_choice_wins() {
+    let base = env::temp_dir().join("builder_last_base");
+
+    let cwd_then_base = AppPath::builder()
+        .default("a.txt")
+        .cwd_relative(true)
+        .base(&base)
+        .build();
+    assert_eq!(&*cwd_then_base, base.join("a.txt").as_path());
+
+    let base_then_cwd = AppPath::builder()
+        .default("a.txt")
+        .base(&base)
+        .cwd_relative(true)
+        .build();
+    assert_eq!(
+        &*base_then_cwd,
+        env::current_dir().unwrap().join("a.txt").as_path()
+    );
+}
+
+#[test]
+fn test_builder_portable_rejects_absolute_default() {
+    let absolute = env::temp_dir().join("builder_portable.toml");
+
+    match AppPath::builder()
+        .default(&absolute)
+        .portable(true)
+        .try_build()
+    {
+        Err(AppPathError::AbsolutePathRejected(path)) => assert_eq!(path, absolute),
+        other => panic!("Expected AbsolutePathRejected, got: {other:?}"),
+    }
+
+    // Without portable strictness the absolute path is used as-is
+    let allowed = AppPath::builder()
+        .default(&absolute)
+        .portable(false)
+        .try_build()
+        .unwrap();
+    assert_eq!(&*allowed, absolute.as_path());
+}
+
+#[test]
+fn test_builder_portable_rejects_absolute_override() {
+    let absolute = env::temp_dir().join("builder_portable_env.toml");
+    env::set_var("BUILDER_TEST_PORTABLE_ENV", &absolute);
+
+    let result = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_PORTABLE_ENV")
+        .portable(true)
+        .cwd_relative(true)
+        .try_build();
+    assert!(matches!(result, Err(AppPathError::AbsolutePathRejected(_))));
+
+    let fn_absolute = absolute.clone();
+    let result = AppPath::builder()
+        .or_fn(move || Some(fn_absolute))
+        .portable(true)
+        .try_build();
+    assert!(matches!(result, Err(AppPathError::AbsolutePathRejected(_))));
+
+    env::remove_var("BUILDER_TEST_PORTABLE_ENV");
+}
+
+#[test]
+#[should_panic(expected = "Failed to build AppPath")]
+fn test_builder_build_panics_on_error() {
+    let absolute = env::temp_dir().join("builder_panic.toml");
+    let _ = AppPath::builder().default(absolute).portable(true).build();
+}
+
+#[test]
+fn test_builder_combined_options() {
+    env::set_var("BUILDER_TEST_COMBINED", "override/settings.toml");
+
+    let built = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_COMBINED")
+        .cwd_relative(true)
+        .portable(true)
+        .build();
+    assert_eq!(
+        &*built,
+        env::current_dir()
+            .unwrap()
+            .join("override/settings.toml")
+            .as_path()
+    );
+
+    env::remove_var("BUILDER_TEST_COMBINED");
+}
+
+#[test]
+fn test_builder_debug_lists_overrides() {
+    let builder = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_DEBUG")
+        .or_fn(|| None::<&str>);
+    let debug = format!("{builder:?}");
+    assert!(debug.contains("BUILDER_TEST_DEBUG"));
+    assert!(debug.contains("<fn>"));
+}
+
+#[test]
+fn test_builder_blank_env_is_unset() {
+    env::set_var("BUILDER_TEST_BLANK", " ");
+
+    let built = AppPath::builder()
+        .default("config.toml")
+        .env("BUILDER_TEST_BLANK")
+        .or_fn(|| Some("from_fn.toml"))
+        .build();
+    assert_eq!(built, AppPath::with("from_fn.toml"));
+
+    env::remove_var("BUILDER_TEST_BLANK");
+}
@@ -0,0 +1,55 @@
+use crate::{AppPath, AppPathShared};
+
+#[test]
+fn test_cache_returns_same_path_as_join() {
+    let base = crate::try_exe_dir().unwrap();
+    for input in ["templates/index.html", "config.toml", "", "a/../b"] {
+        let first = AppPath::with(input);
+        let second = AppPath::with(input);
+        assert_eq!(&*first, base.join(input));
+        assert_eq!(first, second);
+        assert_eq!(&*AppPathShared::with(input), base.join(input));
+    }
+}
+
+#[test]
+fn test_cache_shares_allocation_for_repeated_inputs() {
+    let first = AppPathShared::with("templates/cache_shared_index.html");
+    let second = AppPathShared::with("templates/cache_shared_index.html");
+    assert!(AppPathShared::ptr_eq(&first, &second));
+}
+
+#[test]
+fn test_cache_handles_many_distinct_inputs() {
+    // More inputs than the cache holds; paths must stay correct across evictions
+    let base = crate::try_exe_dir().unwrap();
+    for i in 0..1500 {
+        let input = format!("cache_overflow/{i}.json");
+        assert_eq!(&*AppPath::with(&input), base.join(&input));
+        assert_eq!(&*AppPathShared::with(&input), base.join(&input));
+    }
+}
+
+#[test]
+fn test_cache_concurrent_resolution() {
+    let base = crate::try_exe_dir().unwrap();
+    let handles: Vec<_> = (0..8)
+        .map(|thread| {
+            std::thread::spawn(move || {
+                for round in 0..500 {
+                    // Half the inputs are shared by all threads, half are per thread
+                    let shared = format!("cache_threads/shared/{}.html", round % 20);
+                    let own = format!("cache_threads/{thread}/{}.html", round % 20);
+                    for input in [shared, own] {
+                        assert_eq!(&*AppPath::with(&input), base.join(&input));
+                        assert_eq!(&*AppPathShared::with(&input), base.join(&input));
+                    }
+                }
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
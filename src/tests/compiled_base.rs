@@ -0,0 +1,51 @@
+use crate::functions::with_compiled_fallback;
+use crate::AppPathError;
+use std::env;
+use std::path::PathBuf;
+
+fn simulated_failure() -> Result<PathBuf, AppPathError> {
+    Err(AppPathError::ExecutableNotFound(
+        "simulated current_exe() failure".to_string(),
+    ))
+}
+
+#[test]
+fn test_compiled_base_used_when_detection_fails() {
+    let compiled = env::temp_dir().join("app_path_compiled_base");
+    let compiled_str = compiled.to_str().unwrap();
+
+    let base = with_compiled_fallback(simulated_failure(), Some(compiled_str)).unwrap();
+    assert_eq!(base, compiled);
+}
+
+#[test]
+fn test_compiled_base_ignored_when_detection_succeeds() {
+    let detected = env::temp_dir().join("detected");
+    let compiled = env::temp_dir().join("compiled");
+
+    let base = with_compiled_fallback(Ok(detected.clone()), compiled.to_str()).unwrap();
+    assert_eq!(base, detected);
+}
+
+#[test]
+fn test_compiled_base_unusable_keeps_error() {
+    for compiled in [None, Some(""), Some("relative/base")] {
+        let result = with_compiled_fallback(simulated_failure(), compiled);
+        assert!(
+            matches!(result, Err(AppPathError::ExecutableNotFound(_))),
+            "{compiled:?} must not replace the error"
+        );
+    }
+}
+
+#[cfg(feature = "compiled-base")]
+#[test]
+fn test_compiled_base_reads_build_time_env() {
+    // Whatever was set at build time (if anything) is what the crate embeds
+    let embedded = option_env!("APP_PATH_COMPILED_BASE");
+    let result = with_compiled_fallback(simulated_failure(), embedded);
+    match embedded.map(std::path::Path::new) {
+        Some(base) if base.is_absolute() => assert_eq!(result.unwrap(), base),
+        _ => assert!(result.is_err()),
+    }
+}
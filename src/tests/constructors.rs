@@ -1,4 +1,6 @@
-use crate::AppPath;
+use crate::{AppPath, AppPathError};
+use std::env;
+use std::fs;
 use std::path::Path;
 
 // === Basic Constructor Tests (AppPath::new) ===
@@ -306,3 +308,408 @@ fn test_caching_consistency() {
     assert_eq!(&*first_call, &*second_call);
     assert_eq!(&*second_call, &*third_call);
 }
+
+// === OS String Constructor Tests ===
+
+#[test]
+fn test_from_os_str() {
+    use std::ffi::OsStr;
+
+    let from_os_str = AppPath::from_os_str(OsStr::new("data/users.db"));
+    assert_eq!(from_os_str, AppPath::with("data/users.db"));
+    assert!(from_os_str.is_absolute());
+}
+
+#[test]
+fn test_from_os_string() {
+    use std::ffi::OsString;
+
+    let from_os_string = AppPath::from_os_string(OsString::from("config.toml"));
+    assert_eq!(from_os_string, AppPath::with("config.toml"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_from_os_string_non_utf8() {
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    // 0xFF is never valid in UTF-8
+    let raw = b"data/\xFFfile.bin".to_vec();
+    let os_string = OsString::from_vec(raw.clone());
+    assert!(os_string.to_str().is_none());
+
+    let app_path = AppPath::from_os_string(os_string);
+    assert!(app_path.is_absolute());
+    assert!(app_path.as_os_str().as_bytes().ends_with(&raw));
+
+    let borrowed = AppPath::from_os_str(OsStr::from_bytes(&raw));
+    assert_eq!(app_path, borrowed);
+}
+
+#[test]
+fn test_with_existing_parent_ok() {
+    let temp_dir = env::temp_dir().join("app_path_test_existing_parent");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = temp_dir.join("output.txt");
+    let path = AppPath::with_existing_parent(&file).unwrap();
+    assert_eq!(path, AppPath::with(&file));
+    assert!(!path.exists(), "the file itself need not exist");
+
+    assert!(AppPath::with_existing_parent("beside_exe.txt").is_ok());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_with_existing_parent_missing() {
+    let temp_dir = env::temp_dir().join("app_path_test_existing_parent_missing");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("file.txt"), "not a dir").unwrap();
+
+    let missing = temp_dir.join("missing");
+    match AppPath::with_existing_parent(missing.join("out.txt")) {
+        Err(AppPathError::ParentNotFound(parent)) => assert_eq!(parent, missing),
+        other => panic!("Expected ParentNotFound, got: {other:?}"),
+    }
+    assert!(!missing.exists(), "nothing is created");
+
+    // A file in place of the parent counts as missing
+    assert!(matches!(
+        AppPath::with_existing_parent(temp_dir.join("file.txt/out.txt")),
+        Err(AppPathError::ParentNotFound(_))
+    ));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+// === Custom Base Tests (AppPath::with_base) ===
+
+#[test]
+fn test_with_base_relative_base() {
+    let path = AppPath::with_base("plugins/audio", "settings.toml");
+    assert_eq!(&*path, Path::new("plugins/audio/settings.toml"));
+    assert!(path.is_relative());
+
+    let nested = AppPath::try_with_base("plugins", "audio/presets/a.json").unwrap();
+    assert_eq!(&*nested, Path::new("plugins/audio/presets/a.json"));
+}
+
+#[test]
+fn test_with_base_absolute_base() {
+    let base = env::temp_dir().join("app_path_plugin_root");
+
+    let path = AppPath::with_base(&base, "data/cache.db");
+    assert_eq!(&*path, base.join("data/cache.db"));
+    assert!(path.is_absolute());
+
+    // Works with another AppPath as the base, including the trait machinery
+    let root = AppPath::with_base(&base, "");
+    let child = AppPath::with_base(&root, "file.txt");
+    assert_eq!(child.parent(), Some(root.clone()));
+    assert!(child.starts_with(&root));
+}
+
+#[test]
+fn test_with_base_absolute_path_wins() {
+    let base = env::temp_dir().join("app_path_plugin_root");
+    let absolute = if cfg!(windows) {
+        r"C:\override\config.toml"
+    } else {
+        "/override/config.toml"
+    };
+
+    assert_eq!(&*AppPath::with_base(&base, absolute), Path::new(absolute));
+    assert_eq!(
+        &*AppPath::try_with_base("relative/base", absolute).unwrap(),
+        Path::new(absolute)
+    );
+}
+
+// === Working Directory Tests (AppPath::from_cwd) ===
+
+#[test]
+fn test_from_cwd_resolves_against_current_dir() {
+    super::support::run_isolated("tests::constructors::isolated_from_cwd_in_temp_dir");
+}
+
+#[test]
+#[ignore = "changes the working directory; run via test_from_cwd_resolves_against_current_dir"]
+fn isolated_from_cwd_in_temp_dir() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let temp_dir = env::temp_dir().join("app_path_test_from_cwd");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    env::set_current_dir(&temp_dir).unwrap();
+    let cwd = env::current_dir().unwrap();
+
+    assert_eq!(&*AppPath::from_cwd("report.csv"), cwd.join("report.csv"));
+    assert_eq!(
+        &*AppPath::try_from_cwd("out/data.json").unwrap(),
+        cwd.join("out/data.json")
+    );
+    assert_ne!(AppPath::from_cwd("report.csv"), AppPath::with("report.csv"));
+
+    // A deleted working directory surfaces as an I/O error on platforms that allow it
+    #[cfg(unix)]
+    {
+        fs::remove_dir_all(&temp_dir).unwrap();
+        assert!(matches!(
+            AppPath::try_from_cwd("report.csv"),
+            Err(AppPathError::IoError(_))
+        ));
+    }
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_from_cwd_absolute_passthrough() {
+    let absolute = env::temp_dir().join("app_path_cwd_absolute.txt");
+
+    assert_eq!(&*AppPath::from_cwd(&absolute), absolute.as_path());
+    assert_eq!(
+        &*AppPath::try_from_cwd(&absolute).unwrap(),
+        absolute.as_path()
+    );
+}
+
+// === Executable File Tests (AppPath::exe_file) ===
+
+#[test]
+fn test_exe_file_parent_is_base() {
+    let exe = AppPath::exe_file();
+
+    assert_eq!(exe.parent(), Some(AppPath::new()));
+    assert_eq!(&*exe, env::current_exe().unwrap().as_path());
+    assert!(exe.is_file());
+    assert!(exe.is_absolute());
+}
+
+#[test]
+fn test_try_exe_file_matches_exe_file() {
+    assert_eq!(AppPath::try_exe_file().unwrap(), AppPath::exe_file());
+    assert_ne!(AppPath::exe_file(), AppPath::new());
+}
+
+// === Tilde Expansion Tests (AppPath::with_tilde) ===
+
+#[test]
+fn test_expand_tilde_home_only() {
+    use crate::app_path::constructors::expand_tilde;
+    use std::ffi::OsStr;
+
+    let home = env::temp_dir().join("app_path_home");
+    let expanded = expand_tilde(Path::new("~"), Some(home.as_os_str())).unwrap();
+    assert_eq!(expanded.as_os_str(), home.as_os_str());
+
+    // Missing or empty home variable is an error
+    for missing in [None, Some(OsStr::new(""))] {
+        match expand_tilde(Path::new("~"), missing) {
+            Err(AppPathError::IoError(err)) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::NotFound)
+            }
+            other => panic!("Expected IoError, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_expand_tilde_subpath() {
+    use crate::app_path::constructors::expand_tilde;
+
+    let home = env::temp_dir().join("app_path_home");
+    let expanded = expand_tilde(Path::new("~/logs/app.log"), Some(home.as_os_str())).unwrap();
+    assert_eq!(expanded, home.join("logs/app.log"));
+}
+
+#[test]
+fn test_expand_tilde_only_leading() {
+    use crate::app_path::constructors::expand_tilde;
+
+    // Nothing to expand, so a missing home is irrelevant
+    for literal in ["~file", "~file/sub", "data/~/x", "data/~", "./~"] {
+        let expanded = expand_tilde(Path::new(literal), None).unwrap();
+        assert_eq!(expanded, Path::new(literal), "{literal} must be left alone");
+    }
+    assert_eq!(AppPath::with_tilde("~file"), AppPath::with("~file"));
+    assert_eq!(
+        AppPath::try_with_tilde("data/~/x").unwrap(),
+        AppPath::with("data/~/x")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_with_tilde_uses_home_env() {
+    let Some(home) = env::var_os("HOME").filter(|home| !home.is_empty()) else {
+        return;
+    };
+    assert_eq!(&*AppPath::with_tilde("~"), Path::new(&home));
+    assert_eq!(
+        &*AppPath::try_with_tilde("~/sub").unwrap(),
+        Path::new(&home).join("sub")
+    );
+}
+
+// === Variable Expansion Tests (AppPath::with_expanded) ===
+
+fn test_lookup(var: &str) -> Option<String> {
+    match var {
+        "DATA_ROOT" => Some("/srv/data".to_string()),
+        "NAME" => Some("users".to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_expand_env_vars_set() {
+    use crate::app_path::constructors::expand_env_vars;
+
+    assert_eq!(
+        expand_env_vars("$DATA_ROOT/db/$NAME.db", false, test_lookup).unwrap(),
+        "/srv/data/db/users.db"
+    );
+    assert_eq!(
+        expand_env_vars("no variables here", false, test_lookup).unwrap(),
+        "no variables here"
+    );
+}
+
+#[test]
+fn test_expand_env_vars_braces() {
+    use crate::app_path::constructors::expand_env_vars;
+
+    assert_eq!(
+        expand_env_vars("${DATA_ROOT}/${NAME}_backup.db", false, test_lookup).unwrap(),
+        "/srv/data/users_backup.db"
+    );
+    assert!(expand_env_vars("${DATA_ROOT/db", false, test_lookup).is_err());
+}
+
+#[test]
+fn test_expand_env_vars_unset() {
+    use crate::app_path::constructors::expand_env_vars;
+
+    for input in ["$MISSING/db", "${MISSING}/db", "$DATA_ROOT/$MISSING"] {
+        match expand_env_vars(input, false, test_lookup) {
+            Err(AppPathError::IoError(err)) => {
+                assert_eq!(err.kind(), std::io::ErrorKind::NotFound);
+                assert!(err.to_string().contains("MISSING"));
+            }
+            other => panic!("Expected IoError for {input}, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_expand_env_vars_escape_and_literals() {
+    use crate::app_path::constructors::expand_env_vars;
+
+    assert_eq!(
+        expand_env_vars("prices/$$5.txt", false, test_lookup).unwrap(),
+        "prices/$5.txt"
+    );
+    assert_eq!(
+        expand_env_vars("$$NAME", false, test_lookup).unwrap(),
+        "$NAME"
+    );
+    // A `$` without a name stays literal
+    assert_eq!(
+        expand_env_vars("a$/b$", false, test_lookup).unwrap(),
+        "a$/b$"
+    );
+    assert_eq!(
+        expand_env_vars("$1.txt", false, test_lookup).unwrap(),
+        "$1.txt"
+    );
+}
+
+#[test]
+fn test_expand_env_vars_percent_form() {
+    use crate::app_path::constructors::expand_env_vars;
+
+    assert_eq!(
+        expand_env_vars("%DATA_ROOT%\\db\\%NAME%.db", true, test_lookup).unwrap(),
+        "/srv/data\\db\\users.db"
+    );
+    // Without the Windows syntax, `%` is literal
+    assert_eq!(
+        expand_env_vars("%NAME%", false, test_lookup).unwrap(),
+        "%NAME%"
+    );
+    // Unpaired or non-name `%` stays literal
+    assert_eq!(
+        expand_env_vars("100% sure", true, test_lookup).unwrap(),
+        "100% sure"
+    );
+    assert!(expand_env_vars("%MISSING%", true, test_lookup).is_err());
+}
+
+#[test]
+fn test_with_expanded_resolves_result() {
+    let absolute = env::temp_dir().join("app_path_expanded_root");
+    env::set_var("APP_PATH_TEST_EXPANDED_ABS", &absolute);
+    env::set_var("APP_PATH_TEST_EXPANDED_REL", "relative/dir");
+
+    assert_eq!(
+        &*AppPath::with_expanded("$APP_PATH_TEST_EXPANDED_ABS/users.db"),
+        absolute.join("users.db")
+    );
+    assert_eq!(
+        AppPath::try_with_expanded("${APP_PATH_TEST_EXPANDED_REL}/file.txt").unwrap(),
+        AppPath::with("relative/dir/file.txt")
+    );
+    assert!(AppPath::try_with_expanded("$APP_PATH_TEST_EXPANDED_UNSET_VAR/x").is_err());
+
+    env::remove_var("APP_PATH_TEST_EXPANDED_ABS");
+    env::remove_var("APP_PATH_TEST_EXPANDED_REL");
+}
+
+// === Contained Constructor Tests (AppPath::try_with_contained) ===
+
+#[test]
+fn test_try_with_contained_nested_path() {
+    let plugin = AppPath::try_with_contained("plugins/audio/lib.so").unwrap();
+    assert_eq!(plugin, AppPath::with("plugins/audio/lib.so"));
+
+    // Harmless `.` and `..` inside the base are normalized away
+    let normalized = AppPath::try_with_contained("plugins/./audio/../video/lib.so").unwrap();
+    assert_eq!(normalized, AppPath::with("plugins/video/lib.so"));
+
+    assert_eq!(AppPath::try_with_contained("").unwrap(), AppPath::new());
+}
+
+#[test]
+fn test_try_with_contained_rejects_parent_traversal() {
+    for input in ["..", "../../etc/passwd", "plugins/../../outside.txt"] {
+        match AppPath::try_with_contained(input) {
+            Err(AppPathError::PathEscapesBase { path, base }) => {
+                assert_eq!(path, AppPath::with(input).to_path_buf());
+                assert_eq!(base, AppPath::new().to_path_buf());
+            }
+            other => panic!("Expected PathEscapesBase for {input:?}, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_try_with_contained_rejects_absolute_escape() {
+    let outside = env::temp_dir().join("app_path_contained_outside.txt");
+    let result = AppPath::try_with_contained(&outside);
+    assert!(matches!(result, Err(AppPathError::PathEscapesBase { .. })));
+
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("escapes base directory"));
+}
+
+#[test]
+fn test_try_with_contained_accepts_absolute_inside_base() {
+    let inside = AppPath::with("data/file.txt").into_path_buf();
+    let contained = AppPath::try_with_contained(&inside).unwrap();
+    assert_eq!(&*contained, inside.as_path());
+}
@@ -0,0 +1,150 @@
+use crate::deployment::detect_deployment_mode;
+use crate::{AppPath, DeploymentMode};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn test_deployment_mode_for_test_binary() {
+    // Test binaries live in target/<profile>/deps
+    assert_eq!(AppPath::deployment_mode(), DeploymentMode::Development);
+}
+
+#[test]
+fn test_detect_development_from_target_layout() {
+    let debug = if cfg!(windows) {
+        r"C:\src\myapp\target\debug"
+    } else {
+        "/home/dev/myapp/target/debug"
+    };
+    let cross_release = if cfg!(windows) {
+        r"C:\src\myapp\target\x86_64-pc-windows-msvc\release"
+    } else {
+        "/home/dev/myapp/target/x86_64-unknown-linux-musl/release"
+    };
+
+    assert_eq!(
+        detect_deployment_mode(Path::new(debug)),
+        DeploymentMode::Development
+    );
+    assert_eq!(
+        detect_deployment_mode(Path::new(cross_release)),
+        DeploymentMode::Development
+    );
+}
+
+#[test]
+fn test_detect_development_from_nearby_manifest() {
+    let project = env::temp_dir().join("app_path_test_deployment_manifest");
+    let _ = fs::remove_dir_all(&project);
+    let bin_dir = project.join("bin");
+    fs::create_dir_all(&bin_dir).unwrap();
+    fs::write(project.join("Cargo.toml"), "[package]").unwrap();
+
+    assert_eq!(
+        detect_deployment_mode(&bin_dir),
+        DeploymentMode::Development
+    );
+
+    fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn test_detect_installed_from_system_prefix() {
+    let installed = if cfg!(windows) {
+        r"C:\Program Files\MyApp"
+    } else {
+        "/usr/local/bin"
+    };
+
+    assert_eq!(
+        detect_deployment_mode(Path::new(installed)),
+        DeploymentMode::Installed
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_detect_installed_from_read_only_base() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let base = env::temp_dir().join("app_path_test_deployment_readonly");
+    let _ = fs::remove_dir_all(&base);
+    fs::create_dir_all(&base).unwrap();
+    fs::set_permissions(&base, fs::Permissions::from_mode(0o555)).unwrap();
+
+    let mode = detect_deployment_mode(&base);
+
+    fs::set_permissions(&base, fs::Permissions::from_mode(0o755)).unwrap();
+    fs::remove_dir_all(&base).ok();
+
+    assert_eq!(mode, DeploymentMode::Installed);
+}
+
+#[test]
+fn test_detect_portable_from_writable_folder() {
+    let base = env::temp_dir().join("app_path_test_deployment_portable");
+    let _ = fs::remove_dir_all(&base);
+    fs::create_dir_all(&base).unwrap();
+
+    assert_eq!(detect_deployment_mode(&base), DeploymentMode::Portable);
+
+    fs::remove_dir_all(&base).ok();
+}
+
+#[test]
+fn test_resource_found_in_project_tree_during_development() {
+    // Under `cargo test` the binary lives in target/<profile>/deps of this crate
+    let manifest = AppPath::resource("Cargo.toml");
+    assert!(manifest.is_file());
+    assert!(AppPath::resource("src/lib.rs").is_file());
+}
+
+#[test]
+fn test_resolve_resource_development_layout() {
+    use crate::deployment::resolve_resource;
+
+    let project = env::temp_dir().join("app_path_test_resource_dev");
+    let _ = fs::remove_dir_all(&project);
+    let base = project.join("target/debug");
+    fs::create_dir_all(&base).unwrap();
+    fs::create_dir_all(project.join("assets")).unwrap();
+    fs::write(project.join("Cargo.toml"), "[package]").unwrap();
+    fs::write(project.join("assets/logo.png"), "source tree").unwrap();
+
+    // Missing beside the exe - falls back to the project root
+    let rel = Path::new("assets/logo.png");
+    assert_eq!(
+        resolve_resource(&base, rel),
+        project.join("assets/logo.png")
+    );
+
+    // Present beside the exe - exe-relative copy wins
+    fs::create_dir_all(base.join("assets")).unwrap();
+    fs::write(base.join("assets/logo.png"), "bundled").unwrap();
+    assert_eq!(resolve_resource(&base, rel), base.join("assets/logo.png"));
+
+    // Missing everywhere - behaves like with()
+    let missing = Path::new("assets/missing.png");
+    assert_eq!(resolve_resource(&base, missing), base.join(missing));
+
+    fs::remove_dir_all(&project).ok();
+}
+
+#[test]
+fn test_resolve_resource_installed_layout() {
+    use crate::deployment::resolve_resource;
+
+    let install = env::temp_dir().join("app_path_test_resource_installed");
+    let _ = fs::remove_dir_all(&install);
+    fs::create_dir_all(install.join("assets")).unwrap();
+    fs::write(install.join("assets/logo.png"), "bundled").unwrap();
+
+    let rel = Path::new("assets/logo.png");
+    assert_eq!(resolve_resource(&install, rel), install.join(rel));
+
+    let missing = Path::new("assets/missing.png");
+    assert_eq!(resolve_resource(&install, missing), install.join(missing));
+
+    fs::remove_dir_all(&install).ok();
+}
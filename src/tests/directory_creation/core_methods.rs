@@ -1,4 +1,4 @@
-use crate::AppPath;
+use crate::{AppPath, AppPathError};
 use std::env;
 use std::fs;
 
@@ -90,6 +90,35 @@ fn test_create_dir() {
     fs::remove_dir_all(&temp_dir).ok();
 }
 
+#[test]
+fn test_parent_created_and_dir_created_chain() {
+    let temp_dir = env::temp_dir().join("app_path_test_created_chain");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let log = AppPath::with(temp_dir.join("logs/app.log"));
+    let returned = log.clone().parent_created().unwrap();
+    assert_eq!(returned, log);
+    assert!(temp_dir.join("logs").is_dir());
+    assert!(!log.exists());
+
+    let cache = AppPath::with(temp_dir.join("cache/thumbnails"));
+    let returned = cache.clone().dir_created().unwrap();
+    assert_eq!(returned, cache);
+    assert!(cache.is_dir());
+
+    // Errors pass through unchanged
+    let blocker = AppPath::with(temp_dir.join("blocker"));
+    blocker.write("file").unwrap();
+    assert!(AppPath::with(temp_dir.join("blocker/child.txt"))
+        .parent_created()
+        .is_err());
+    assert!(AppPath::with(temp_dir.join("blocker/child"))
+        .dir_created()
+        .is_err());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
 #[test]
 fn test_new_directory_creation_methods() {
     let temp_dir = env::temp_dir().join("app_path_test_new_methods");
@@ -224,3 +253,420 @@ fn test_create_dir_all_preserves_existing_behavior() {
     // Cleanup
     fs::remove_dir_all(&temp_dir).ok();
 }
+
+#[test]
+fn test_existing_ancestor() {
+    let temp_dir = env::temp_dir().join("app_path_test_existing_ancestor");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("a/b")).unwrap();
+
+    // Deepest existing ancestor of a partially existing tree
+    let partial = AppPath::with(temp_dir.join("a/b/c/d/file.txt"));
+    let ancestor = partial.existing_ancestor().unwrap();
+    assert_eq!(&*ancestor, temp_dir.join("a/b").as_path());
+
+    // An existing path is its own deepest existing ancestor
+    let existing = AppPath::with(temp_dir.join("a"));
+    assert_eq!(existing.existing_ancestor().unwrap(), existing);
+
+    // Falls back to the temp dir when nothing below it exists
+    let missing = AppPath::with(temp_dir.join("x/y/z"));
+    assert_eq!(&*missing.existing_ancestor().unwrap(), temp_dir.as_path());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_find_upwards() {
+    let temp_dir = env::temp_dir().join("app_path_test_find_upwards");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("project/src/nested/deep")).unwrap();
+    fs::write(temp_dir.join("project/marker.toml"), "").unwrap();
+    fs::write(temp_dir.join("project/src/nested/file.rs"), "").unwrap();
+
+    // Found a few levels up from a directory
+    let deep = AppPath::with(temp_dir.join("project/src/nested/deep"));
+    let found = deep.find_upwards("marker.toml").unwrap();
+    assert_eq!(&*found, temp_dir.join("project/marker.toml").as_path());
+
+    // A file starts the search at its parent directory
+    let file = AppPath::with(temp_dir.join("project/src/nested/file.rs"));
+    assert_eq!(file.find_upwards("marker.toml"), Some(found.clone()));
+
+    // The closest match wins
+    fs::write(temp_dir.join("project/src/marker.toml"), "").unwrap();
+    assert_eq!(
+        &*deep.find_upwards("marker.toml").unwrap(),
+        temp_dir.join("project/src/marker.toml").as_path()
+    );
+
+    // Nothing found all the way up to the root
+    assert_eq!(deep.find_upwards("app_path_no_such_marker.toml"), None);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_create_dir_error_reports_existing_ancestor() {
+    let temp_dir = env::temp_dir().join("app_path_test_create_error_context");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("blocker"), "file, not a directory").unwrap();
+
+    let blocked = AppPath::with(temp_dir.join("blocker/nested/dir"));
+    let err = blocked.create_dir().unwrap_err().to_string();
+    assert!(err.contains("deepest existing ancestor"));
+    assert!(err.contains("blocker"));
+
+    let blocked_file = AppPath::with(temp_dir.join("blocker/nested/file.txt"));
+    let err = blocked_file.create_parents().unwrap_err().to_string();
+    assert!(err.contains("deepest existing ancestor"));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_create_dir_error_keeps_os_error() {
+    use std::error::Error;
+
+    let temp_dir = env::temp_dir().join("app_path_test_create_error_os_code");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("blocker"), "file, not a directory").unwrap();
+    let expected = fs::create_dir_all(temp_dir.join("blocker/nested"))
+        .unwrap_err()
+        .raw_os_error();
+    assert!(expected.is_some());
+
+    let err = AppPath::with(temp_dir.join("blocker/nested"))
+        .create_dir()
+        .unwrap_err();
+    let AppPathError::IoError(io_err) = &err else {
+        panic!("expected an I/O error, got {err:?}");
+    };
+    let original = io_err
+        .source()
+        .and_then(|source| source.downcast_ref::<std::io::Error>())
+        .expect("original error is kept as the source");
+    assert_eq!(original.raw_os_error(), expected);
+    assert_eq!(io_err.kind(), original.kind());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_subdirs_and_files_partition_children() {
+    let temp_dir = env::temp_dir().join("app_path_test_subdirs_files");
+    let _ = fs::remove_dir_all(&temp_dir);
+    for dir in ["zeta", "alpha", "mid/nested"] {
+        fs::create_dir_all(temp_dir.join(dir)).unwrap();
+    }
+    for file in ["b.txt", "a.toml", "mid/nested/deep.txt"] {
+        fs::write(temp_dir.join(file), "content").unwrap();
+    }
+
+    let root = AppPath::with(&temp_dir);
+
+    let subdirs = root.subdirs().unwrap();
+    assert_eq!(
+        subdirs,
+        vec![
+            AppPath::with(temp_dir.join("alpha")),
+            AppPath::with(temp_dir.join("mid")),
+            AppPath::with(temp_dir.join("zeta")),
+        ]
+    );
+
+    // Only immediate children, sorted
+    let files = root.files().unwrap();
+    assert_eq!(
+        files,
+        vec![
+            AppPath::with(temp_dir.join("a.toml")),
+            AppPath::with(temp_dir.join("b.txt")),
+        ]
+    );
+
+    assert!(AppPath::with(temp_dir.join("alpha"))
+        .files()
+        .unwrap()
+        .is_empty());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_subdirs_and_files_errors() {
+    let temp_dir = env::temp_dir().join("app_path_test_subdirs_errors");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("file.txt"), "content").unwrap();
+
+    let missing = AppPath::with(temp_dir.join("missing"));
+    let err = missing.subdirs().unwrap_err();
+    assert!(err.to_string().contains("missing"));
+
+    let not_a_dir = AppPath::with(temp_dir.join("file.txt"));
+    assert!(not_a_dir.files().is_err());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_canonical_parent_creates_and_canonicalizes() {
+    let temp_dir = env::temp_dir().join("app_path_test_canonical_parent");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    // A `..` detour ensures the result is not just the lexical parent
+    let file = AppPath::with(temp_dir.join("a/../nested/deeper/file.db"));
+    let parent = file.canonical_parent().unwrap();
+
+    assert!(parent.is_dir());
+    assert_eq!(
+        parent.to_path_buf(),
+        fs::canonicalize(temp_dir.join("nested/deeper")).unwrap()
+    );
+    assert!(!parent
+        .components()
+        .any(|c| matches!(c, std::path::Component::ParentDir)));
+    assert!(!file.exists(), "only the parent is created");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_canonical_parent_resolves_symlinks() {
+    let temp_dir = env::temp_dir().join("app_path_test_canonical_parent_link");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("real")).unwrap();
+    std::os::unix::fs::symlink(temp_dir.join("real"), temp_dir.join("link")).unwrap();
+
+    let parent = AppPath::with(temp_dir.join("link/sub/file.txt"))
+        .canonical_parent()
+        .unwrap();
+    assert_eq!(
+        parent.to_path_buf(),
+        fs::canonicalize(temp_dir.join("real/sub")).unwrap()
+    );
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_canonical_parent_root_errors() {
+    let root = if cfg!(windows) { r"C:\" } else { "/" };
+    let err = AppPath::with(root).canonical_parent().unwrap_err();
+    assert!(err.to_string().contains("no parent"));
+}
+
+#[test]
+fn test_count_entries_and_files() {
+    let temp_dir = env::temp_dir().join("app_path_test_count_entries");
+    let _ = fs::remove_dir_all(&temp_dir);
+    for dir in ["a/b/c", "d", "empty"] {
+        fs::create_dir_all(temp_dir.join(dir)).unwrap();
+    }
+    for file in [
+        "root.txt",
+        "a/one.txt",
+        "a/b/two.txt",
+        "a/b/c/three.txt",
+        "d/four.txt",
+    ] {
+        fs::write(temp_dir.join(file), "x").unwrap();
+    }
+
+    let root = AppPath::with(&temp_dir);
+    // Directories: a, a/b, a/b/c, d, empty
+    assert_eq!(root.count_entries().unwrap(), 10);
+    assert_eq!(root.count_files().unwrap(), 5);
+
+    let sub = AppPath::with(temp_dir.join("a"));
+    assert_eq!(sub.count_entries().unwrap(), 5);
+    assert_eq!(sub.count_files().unwrap(), 3);
+
+    let empty = AppPath::with(temp_dir.join("empty"));
+    assert_eq!(empty.count_entries().unwrap(), 0);
+    assert_eq!(empty.count_files().unwrap(), 0);
+
+    assert!(AppPath::with(temp_dir.join("missing"))
+        .count_entries()
+        .is_err());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_bundle_entries_inventory() {
+    let temp_dir = env::temp_dir().join("app_path_test_bundle_entries");
+    let _ = fs::remove_dir_all(&temp_dir);
+    for dir in ["assets/img", "config", "empty"] {
+        fs::create_dir_all(temp_dir.join(dir)).unwrap();
+    }
+    for file in [
+        "app.bin",
+        "Cargo.lock",
+        "assets/img/logo.png",
+        "config/app.toml",
+        "config/counter.seq",
+    ] {
+        fs::write(temp_dir.join(file), "x").unwrap();
+    }
+
+    // Artifacts from the crate's own helpers
+    let counter = AppPath::with(temp_dir.join("config/counter.seq"));
+    fs::write(counter.temp_sibling(), "partial").unwrap();
+    fs::write(temp_dir.join("config/counter.seq.lock"), "").unwrap();
+
+    let root = AppPath::with(&temp_dir);
+    let entries = root.bundle_entries().unwrap();
+
+    let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "Cargo.lock",
+            "app.bin",
+            "assets/img/logo.png",
+            "config/app.toml",
+            "config/counter.seq",
+        ]
+    );
+    for (name, path) in &entries {
+        assert_eq!(*path, AppPath::with(temp_dir.join(name)));
+        assert!(path.is_file());
+    }
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_temp_sibling_name_detection() {
+    let temp = AppPath::with("data/users.db").temp_sibling();
+    let name = temp.file_name().unwrap().to_str().unwrap();
+    assert!(AppPath::is_temp_sibling_name(name));
+
+    for name in [
+        "users.db",
+        ".hidden.tmp",
+        ".a.1.2.tmp",
+        ".a.b.c.d.tmp",
+        "a.1.2.3.tmp",
+    ] {
+        assert!(!AppPath::is_temp_sibling_name(name), "{name}");
+    }
+}
+
+#[test]
+fn test_canonicalize_plain_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_canonicalize");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("sub")).unwrap();
+    fs::write(temp_dir.join("file.txt"), "content").unwrap();
+
+    let path = AppPath::with(temp_dir.join("sub/../file.txt"));
+    let canonical = path.canonicalize().unwrap();
+
+    assert!(canonical.is_absolute());
+    assert_eq!(
+        canonical.to_path_buf(),
+        fs::canonicalize(temp_dir.join("file.txt")).unwrap()
+    );
+    // Chaining keeps working with AppPath
+    assert_eq!(
+        canonical.parent().unwrap(),
+        AppPath::with(fs::canonicalize(&temp_dir).unwrap())
+    );
+
+    let missing = AppPath::with(temp_dir.join("missing.txt"));
+    let err = missing.canonicalize().unwrap_err();
+    assert!(err.to_string().contains("missing.txt"));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_canonicalize_resolves_symlink() {
+    let temp_dir = env::temp_dir().join("app_path_test_canonicalize_link");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("target.toml"), "x = 1").unwrap();
+    std::os::unix::fs::symlink(temp_dir.join("target.toml"), temp_dir.join("link.toml")).unwrap();
+
+    let canonical = AppPath::with(temp_dir.join("link.toml"))
+        .canonicalize()
+        .unwrap();
+    assert_eq!(
+        canonical.to_path_buf(),
+        fs::canonicalize(temp_dir.join("target.toml")).unwrap()
+    );
+    assert_eq!(canonical.file_name().unwrap(), "target.toml");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_remove_existing_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_remove_file");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = AppPath::with(temp_dir.join("stale.lock"));
+    fs::write(&file, "pid").unwrap();
+    file.remove_file().unwrap();
+    assert!(!file.exists());
+
+    let other = AppPath::with(temp_dir.join("other.lock"));
+    fs::write(&other, "pid").unwrap();
+    other.remove_if_exists().unwrap();
+    assert!(!other.exists());
+
+    // A directory is not a file
+    assert!(matches!(
+        AppPath::with(&temp_dir).remove_file(),
+        Err(AppPathError::IoError(_))
+    ));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_remove_nested_directory_tree() {
+    let temp_dir = env::temp_dir().join("app_path_test_remove_tree");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let tree = AppPath::with(temp_dir.join("cache"));
+    fs::create_dir_all(tree.join("a/b/c")).unwrap();
+    fs::write(tree.join("a/b/c/deep.bin"), "x").unwrap();
+    fs::write(tree.join("top.txt"), "x").unwrap();
+
+    tree.remove_dir_all().unwrap();
+    assert!(!tree.exists());
+    assert!(temp_dir.exists());
+
+    let second = AppPath::with(temp_dir.join("logs"));
+    fs::create_dir_all(second.join("2024/01")).unwrap();
+    second.remove_if_exists().unwrap();
+    assert!(!second.exists());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_remove_missing_path() {
+    let missing = AppPath::with(env::temp_dir().join("app_path_test_remove_missing/nothing"));
+
+    for result in [missing.remove_file(), missing.remove_dir_all()] {
+        match result {
+            Err(AppPathError::IoError(io_err)) => {
+                assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+                assert!(io_err.to_string().contains("app_path_test_remove_missing"));
+            }
+            other => panic!("Expected IoError, got: {other:?}"),
+        }
+    }
+
+    missing.remove_if_exists().unwrap();
+    missing.remove_if_exists().unwrap();
+}
@@ -0,0 +1,72 @@
+use crate::{AppPath, PathKind};
+use std::env;
+use std::fs;
+
+#[test]
+fn test_ensure_layout_from_scratch_and_idempotent() {
+    let temp_dir = env::temp_dir().join("app_path_test_ensure_layout");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let cache = temp_dir.join("data/cache");
+    let logs = temp_dir.join("logs");
+    let config = temp_dir.join("config/app.toml");
+    let (cache, logs, config) = (
+        cache.to_str().unwrap(),
+        logs.to_str().unwrap(),
+        config.to_str().unwrap(),
+    );
+    let spec = [
+        (PathKind::Dir, cache),
+        (PathKind::Dir, logs),
+        (PathKind::File, config),
+    ];
+
+    let paths = AppPath::ensure_layout(&spec).unwrap();
+    assert_eq!(
+        paths,
+        vec![
+            AppPath::with(cache),
+            AppPath::with(logs),
+            AppPath::with(config)
+        ]
+    );
+    assert!(paths[0].is_dir());
+    assert!(paths[1].is_dir());
+    assert!(temp_dir.join("config").is_dir());
+    assert!(!paths[2].exists(), "file entries must not be created");
+
+    // Existing content is preserved on re-run
+    fs::write(&paths[2], "key = 1").unwrap();
+    let again = AppPath::ensure_layout(&spec).unwrap();
+    assert_eq!(again, paths);
+    assert_eq!(fs::read_to_string(&paths[2]).unwrap(), "key = 1");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_ensure_layout_collects_failures() {
+    let temp_dir = env::temp_dir().join("app_path_test_ensure_layout_fail");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("blocker"), "not a directory").unwrap();
+
+    let bad_dir = temp_dir.join("blocker/sub");
+    let bad_file = temp_dir.join("blocker/nested/file.txt");
+    let good = temp_dir.join("good");
+    let spec = [
+        (PathKind::Dir, bad_dir.to_str().unwrap()),
+        (PathKind::Dir, good.to_str().unwrap()),
+        (PathKind::File, bad_file.to_str().unwrap()),
+    ];
+
+    let err = AppPath::ensure_layout(&spec).unwrap_err().to_string();
+    assert!(err.contains("2 of 3"), "unexpected message: {err}");
+    assert!(err.contains(bad_dir.to_str().unwrap()));
+    assert!(err.contains(bad_file.to_str().unwrap()));
+
+    // Entries after a failure are still attempted
+    assert!(good.is_dir());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
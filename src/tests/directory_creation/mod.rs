@@ -1 +1,2 @@
 pub mod core_methods;
+pub mod layout;
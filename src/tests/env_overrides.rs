@@ -0,0 +1,144 @@
+use crate::{app_path, try_app_path, AppPath};
+
+fn count(var: &str) -> usize {
+    AppPath::registered_env_overrides()
+        .iter()
+        .filter(|&&registered| registered == var)
+        .count()
+}
+
+#[test]
+fn test_register_env_override_without_duplicates() {
+    AppPath::register_env_override("APP_PATH_TEST_REGISTRY_A");
+    AppPath::register_env_override("APP_PATH_TEST_REGISTRY_B");
+    AppPath::register_env_override("APP_PATH_TEST_REGISTRY_A");
+
+    assert_eq!(count("APP_PATH_TEST_REGISTRY_A"), 1);
+    assert_eq!(count("APP_PATH_TEST_REGISTRY_B"), 1);
+
+    let registered = AppPath::registered_env_overrides();
+    let a = registered
+        .iter()
+        .position(|&var| var == "APP_PATH_TEST_REGISTRY_A")
+        .unwrap();
+    let b = registered
+        .iter()
+        .position(|&var| var == "APP_PATH_TEST_REGISTRY_B")
+        .unwrap();
+    assert!(a < b, "variables keep first-registration order");
+}
+
+#[test]
+fn test_macros_register_env_overrides() {
+    let _ = app_path!("config.toml", env = "APP_PATH_TEST_REGISTRY_MACRO");
+    let _ = app_path!("config.toml", env = "APP_PATH_TEST_REGISTRY_MACRO");
+    let dynamic = String::from("APP_PATH_TEST_REGISTRY_TRY_MACRO");
+    let _ = try_app_path!("data", env = &dynamic).unwrap();
+
+    assert_eq!(count("APP_PATH_TEST_REGISTRY_MACRO"), 1);
+    assert_eq!(count("APP_PATH_TEST_REGISTRY_TRY_MACRO"), 1);
+}
+
+#[test]
+fn test_builder_registers_env_overrides() {
+    let _ = AppPath::builder()
+        .default("data")
+        .env("APP_PATH_TEST_REGISTRY_BUILDER")
+        .env("APP_PATH_TEST_REGISTRY_BUILDER")
+        .build();
+
+    assert_eq!(count("APP_PATH_TEST_REGISTRY_BUILDER"), 1);
+}
+
+#[test]
+fn test_init_base_from_env_ignores_unusable_values() {
+    // None of these seed the base, so the process-wide cache is left untouched
+    assert!(!AppPath::init_base_from_env("APP_PATH_TEST_BASE_UNSET"));
+    assert_eq!(count("APP_PATH_TEST_BASE_UNSET"), 1);
+
+    std::env::set_var("APP_PATH_TEST_BASE_BLANK", "  ");
+    assert!(!AppPath::init_base_from_env("APP_PATH_TEST_BASE_BLANK"));
+
+    std::env::set_var("APP_PATH_TEST_BASE_RELATIVE", "relative/base");
+    assert!(!AppPath::init_base_from_env("APP_PATH_TEST_BASE_RELATIVE"));
+}
+
+#[test]
+fn test_init_base_from_env_seeds_base() {
+    super::support::run_isolated("tests::env_overrides::isolated_init_base_from_env_seeds_base");
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_init_base_from_env_seeds_base"]
+fn isolated_init_base_from_env_seeds_base() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let base = std::env::temp_dir().join("app_path_test_env_base");
+    std::env::set_var("APP_PATH_TEST_ENV_BASE", &base);
+
+    assert!(AppPath::init_base_from_env("APP_PATH_TEST_ENV_BASE"));
+    assert_eq!(&*AppPath::new(), base.as_path());
+    assert_eq!(&*AppPath::with("config.toml"), base.join("config.toml"));
+    assert_eq!(&*app_path!("data/users.db"), base.join("data/users.db"));
+    assert_eq!(crate::exe_dir(), base.as_path());
+
+    // Seeding again with the same directory is accepted; a different one is not
+    assert!(AppPath::init_base_from_env("APP_PATH_TEST_ENV_BASE"));
+    std::env::set_var("APP_PATH_TEST_ENV_BASE_OTHER", std::env::temp_dir());
+    assert!(!AppPath::init_base_from_env("APP_PATH_TEST_ENV_BASE_OTHER"));
+    assert_eq!(&*AppPath::new(), base.as_path());
+}
+
+#[test]
+fn test_init_base_from_env_after_first_use() {
+    super::support::run_isolated(
+        "tests::env_overrides::isolated_init_base_from_env_after_first_use",
+    );
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_init_base_from_env_after_first_use"]
+fn isolated_init_base_from_env_after_first_use() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let exe_base = AppPath::new();
+    std::env::set_var(
+        "APP_PATH_TEST_ENV_BASE_LATE",
+        std::env::temp_dir().join("app_path_test_env_base_late"),
+    );
+
+    // Paths resolved before must stay valid, so a late seed is refused
+    assert!(!AppPath::init_base_from_env("APP_PATH_TEST_ENV_BASE_LATE"));
+    assert_eq!(AppPath::new(), exe_base);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_init_base_from_env_with_testing_override() {
+    super::support::run_isolated(
+        "tests::env_overrides::isolated_init_base_from_env_with_testing_override",
+    );
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[ignore = "mutates process-global state; run via test_init_base_from_env_with_testing_override"]
+fn isolated_init_base_from_env_with_testing_override() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let env_base = std::env::temp_dir().join("app_path_test_env_base_seeded");
+    std::env::set_var("APP_PATH_TEST_ENV_BASE_SEEDED", &env_base);
+    assert!(AppPath::init_base_from_env("APP_PATH_TEST_ENV_BASE_SEEDED"));
+    assert_eq!(&*AppPath::with("config.toml"), env_base.join("config.toml"));
+
+    // The testing setter takes precedence, and clearing it falls back to the env base
+    let sandbox = std::env::temp_dir().join("app_path_test_env_base_sandbox");
+    AppPath::set_exe_dir_for_testing(sandbox.clone());
+    assert_eq!(&*AppPath::with("config.toml"), sandbox.join("config.toml"));
+
+    AppPath::clear_exe_dir_override();
+    assert_eq!(&*AppPath::with("config.toml"), env_base.join("config.toml"));
+}
@@ -427,3 +427,177 @@ fn test_create_parents_with_file_blocking_parent() {
         _ => panic!("Expected IoError when file blocks parent creation, got: {result:?}"),
     }
 }
+
+#[test]
+fn test_io_kind_accessor() {
+    use std::io::ErrorKind;
+
+    let io_error = AppPathError::from(std::io::Error::from(ErrorKind::NotFound));
+    assert_eq!(io_error.io_kind(), Some(ErrorKind::NotFound));
+
+    let exec_error = AppPathError::ExecutableNotFound("exec error".to_string());
+    assert_eq!(exec_error.io_kind(), None);
+    assert_eq!(
+        AppPathError::ParentNotFound("missing".into()).io_kind(),
+        None
+    );
+}
+
+#[test]
+fn test_permission_denied_survives_path_context() {
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+
+    let path = PathBuf::from("/restricted/config.toml");
+    let app_error = AppPathError::from((
+        std::io::Error::new(ErrorKind::PermissionDenied, "access denied"),
+        &path,
+    ));
+
+    assert!(matches!(
+        app_error.io_kind(),
+        Some(ErrorKind::PermissionDenied)
+    ));
+    assert!(app_error.to_string().contains("access denied"));
+    assert!(app_error.to_string().contains("config.toml"));
+
+    let source = app_error.source().expect("IoError has a source");
+    let io_source = source.downcast_ref::<std::io::Error>().unwrap();
+    assert_eq!(io_source.kind(), ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn test_into_io_error_round_trip() {
+    use std::io::ErrorKind;
+
+    let original = std::io::Error::new(ErrorKind::PermissionDenied, "access denied");
+    let app_error = AppPathError::from(original);
+    let io_error: std::io::Error = app_error.into();
+    assert_eq!(io_error.kind(), ErrorKind::PermissionDenied);
+    assert_eq!(io_error.to_string(), "access denied");
+
+    // Path context added by the crate is kept
+    let path = std::path::PathBuf::from("data/users.db");
+    let with_context = AppPathError::from((std::io::Error::from(ErrorKind::NotFound), &path));
+    let io_error = std::io::Error::from(with_context);
+    assert_eq!(io_error.kind(), ErrorKind::NotFound);
+    assert!(io_error.to_string().contains("users.db"));
+}
+
+#[test]
+fn test_into_io_error_other_variants() {
+    use std::io::ErrorKind;
+
+    let cases = [
+        (
+            AppPathError::ExecutableNotFound("no exe".to_string()),
+            ErrorKind::Other,
+            "Failed to determine executable location",
+        ),
+        (
+            AppPathError::InvalidExecutablePath("empty".to_string()),
+            ErrorKind::Other,
+            "Invalid executable path",
+        ),
+        (
+            AppPathError::AbsolutePathRejected("/etc/passwd".into()),
+            ErrorKind::InvalidInput,
+            "Absolute path rejected",
+        ),
+        (
+            AppPathError::ParentNotFound("missing/dir".into()),
+            ErrorKind::NotFound,
+            "Parent directory does not exist",
+        ),
+        (
+            AppPathError::InvalidPath {
+                path: "bad\0name".into(),
+                reason: "contains an interior NUL byte".to_string(),
+            },
+            ErrorKind::InvalidInput,
+            "interior NUL byte",
+        ),
+    ];
+
+    for (app_error, kind, message) in cases {
+        let io_error = std::io::Error::from(app_error);
+        assert_eq!(io_error.kind(), kind);
+        assert!(io_error.to_string().contains(message), "{io_error}");
+        // The original error stays reachable for downcasting
+        assert!(io_error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<AppPathError>())
+            .is_some());
+    }
+}
+
+#[test]
+fn test_question_mark_in_io_result_function() {
+    fn resolve() -> std::io::Result<AppPath> {
+        let config = AppPath::try_with("config.toml")?;
+        Ok(config)
+    }
+
+    assert_eq!(resolve().unwrap(), AppPath::with("config.toml"));
+}
+
+#[test]
+fn test_interior_nul_rejected_by_fallible_constructors() {
+    let input = "data/evil\0name.txt";
+
+    for result in [
+        AppPath::try_with(input),
+        AppPath::try_with_base(std::env::temp_dir(), input),
+        AppPath::try_from_cwd(input),
+    ] {
+        match result {
+            Err(AppPathError::InvalidPath { path, reason }) => {
+                assert_eq!(path, std::path::Path::new(input));
+                assert!(reason.contains("NUL"), "{reason}");
+            }
+            other => panic!("Expected InvalidPath, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_interior_nul_error_message() {
+    let error = AppPath::try_with("evil\0.txt").unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("Invalid path"), "{message}");
+    assert!(message.contains("interior NUL byte"), "{message}");
+    // The NUL byte is escaped rather than printed raw
+    assert!(message.contains("\\0"), "{message}");
+}
+
+#[test]
+#[should_panic(expected = "interior NUL byte")]
+fn test_interior_nul_panics_in_infallible_constructor() {
+    let _ = AppPath::with("evil\0.txt");
+}
+
+#[cfg(unix)]
+#[test]
+fn test_interior_nul_in_non_utf8_path() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let input = OsStr::from_bytes(b"bad\xff\x00name");
+    assert!(matches!(
+        AppPath::try_with(input),
+        Err(AppPathError::InvalidPath { .. })
+    ));
+}
+
+#[cfg(windows)]
+#[test]
+fn test_interior_nul_in_wide_path() {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+
+    let input = OsString::from_wide(&[0xD800, 0, u16::from(b'a')]);
+    assert!(matches!(
+        AppPath::try_with(input),
+        Err(AppPathError::InvalidPath { .. })
+    ));
+}
@@ -0,0 +1,906 @@
+use crate::app_path::file_ops::format_size;
+use crate::{AppPath, AppPathError};
+use std::env;
+use std::fs;
+
+#[test]
+fn test_human_size_known_files() {
+    let temp_dir = env::temp_dir().join("app_path_test_human_size");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let cases: [(u64, &str, &str); 6] = [
+        (0, "0 B", "0 B"),
+        (999, "999 B", "999 B"),
+        (1023, "1023 B", "1.02 kB"),
+        (1024, "1.00 KiB", "1.02 kB"),
+        (1536, "1.50 KiB", "1.54 kB"),
+        (1_048_576, "1.00 MiB", "1.05 MB"),
+    ];
+
+    for (size, binary, decimal) in cases {
+        let file = AppPath::with(temp_dir.join(format!("{size}.bin")));
+        fs::write(&file, vec![0u8; size as usize]).unwrap();
+
+        assert_eq!(file.human_size().unwrap(), binary, "binary size of {size}");
+        assert_eq!(file.human_size_si().unwrap(), decimal, "SI size of {size}");
+    }
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_format_size_unit_boundaries() {
+    let binary = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+    assert_eq!(format_size(1023, 1024, &binary), "1023 B");
+    assert_eq!(format_size(1024, 1024, &binary), "1.00 KiB");
+    // Rounds up into the next unit instead of showing "1024.00 KiB"
+    assert_eq!(format_size(1_048_575, 1024, &binary), "1.00 MiB");
+    assert_eq!(format_size(1_468_006, 1024, &binary), "1.40 MiB");
+    assert_eq!(
+        format_size(5 * 1024 * 1024 * 1024, 1024, &binary),
+        "5.00 GiB"
+    );
+    assert_eq!(format_size(u64::MAX, 1024, &binary), "16.00 EiB");
+}
+
+#[test]
+fn test_human_size_missing_file() {
+    let missing = AppPath::with("definitely_missing_human_size.bin");
+
+    match missing.human_size() {
+        Err(AppPathError::IoError(io_err)) => {
+            assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+            assert!(io_err
+                .to_string()
+                .contains("definitely_missing_human_size.bin"));
+        }
+        other => panic!("Expected IoError, got: {other:?}"),
+    }
+    assert!(missing.human_size_si().is_err());
+}
+
+#[test]
+fn test_read_to_string_and_bytes_present_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_read_present");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let config = AppPath::with(temp_dir.join("config.toml"));
+    fs::write(&config, "name = \"app\"\n").unwrap();
+    assert_eq!(config.read_to_string().unwrap(), "name = \"app\"\n");
+    assert_eq!(config.read_bytes().unwrap(), b"name = \"app\"\n");
+
+    let blob = AppPath::with(temp_dir.join("blob.bin"));
+    fs::write(&blob, [0xff, 0x00, 0xfe]).unwrap();
+    assert_eq!(blob.read_bytes().unwrap(), [0xff, 0x00, 0xfe]);
+    // Not valid UTF-8
+    assert!(matches!(
+        blob.read_to_string(),
+        Err(AppPathError::IoError(_))
+    ));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_read_missing_file_mentions_path() {
+    let missing = AppPath::with("definitely_missing_read_to_string.toml");
+
+    for result in [
+        missing.read_to_string().map(drop),
+        missing.read_bytes().map(drop),
+    ] {
+        match result {
+            Err(AppPathError::IoError(io_err)) => {
+                assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+                assert!(io_err
+                    .to_string()
+                    .contains("definitely_missing_read_to_string.toml"));
+            }
+            other => panic!("Expected IoError, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_read_directory_as_file_fails() {
+    let temp_dir = env::temp_dir().join("app_path_test_read_directory");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let dir = AppPath::with(&temp_dir);
+    for result in [dir.read_to_string().map(drop), dir.read_bytes().map(drop)] {
+        match result {
+            Err(AppPathError::IoError(io_err)) => {
+                assert!(io_err.to_string().contains("app_path_test_read_directory"));
+            }
+            other => panic!("Expected IoError, got: {other:?}"),
+        }
+    }
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_write_creates_nested_parents() {
+    let temp_dir = env::temp_dir().join("app_path_test_write_nested");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let config = AppPath::with(temp_dir.join("a/b/c/config.toml"));
+    config.write("key = 1").unwrap();
+    assert_eq!(fs::read_to_string(&config).unwrap(), "key = 1");
+
+    let state = AppPath::with(temp_dir.join("x/y/state.bin"));
+    state.write_atomic([1u8, 2, 3]).unwrap();
+    assert_eq!(fs::read(&state).unwrap(), [1, 2, 3]);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_write_overwrites_existing_content() {
+    let temp_dir = env::temp_dir().join("app_path_test_write_overwrite");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let plain = AppPath::with(temp_dir.join("plain.txt"));
+    plain.write("a much longer first version").unwrap();
+    plain.write("short").unwrap();
+    assert_eq!(plain.read_to_string().unwrap(), "short");
+
+    let atomic = AppPath::with(temp_dir.join("atomic.txt"));
+    atomic.write_atomic("a much longer first version").unwrap();
+    atomic.write_atomic(String::from("short")).unwrap();
+    assert_eq!(atomic.read_to_string().unwrap(), "short");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_write_atomic_leaves_no_temp_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_write_atomic_clean");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let state = AppPath::with(temp_dir.join("state.json"));
+    for round in 0..5 {
+        state
+            .write_atomic(format!("{{\"round\": {round}}}"))
+            .unwrap();
+    }
+
+    let names: Vec<_> = fs::read_dir(&temp_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, ["state.json"]);
+    assert_eq!(state.read_to_string().unwrap(), "{\"round\": 4}");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_write_atomic_failure_cleans_up_and_mentions_path() {
+    let temp_dir = env::temp_dir().join("app_path_test_write_atomic_failure");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    // Renaming a file over a non-empty directory fails
+    let target = AppPath::with(temp_dir.join("occupied"));
+    fs::create_dir_all(target.join("child")).unwrap();
+
+    match target.write_atomic("data") {
+        Err(AppPathError::IoError(io_err)) => {
+            assert!(io_err.to_string().contains("occupied"));
+        }
+        other => panic!("Expected IoError, got: {other:?}"),
+    }
+    assert!(matches!(
+        target.write("data"),
+        Err(AppPathError::IoError(_))
+    ));
+
+    let names: Vec<_> = fs::read_dir(&temp_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(names, ["occupied"]);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_metadata_len_and_modified() {
+    let temp_dir = env::temp_dir().join("app_path_test_metadata");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let before = std::time::SystemTime::now() - std::time::Duration::from_secs(5);
+    let file = AppPath::with(temp_dir.join("cache.bin"));
+    fs::write(&file, vec![7u8; 1234]).unwrap();
+
+    let metadata = file.metadata().unwrap();
+    assert!(metadata.is_file());
+    assert_eq!(file.len().unwrap(), 1234);
+    assert_eq!(metadata.len(), 1234);
+
+    let modified = file.modified().unwrap();
+    assert!(modified >= before);
+    assert_eq!(modified, metadata.modified().unwrap());
+
+    assert!(AppPath::with(&temp_dir).metadata().unwrap().is_dir());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_metadata_missing_file_mentions_path() {
+    let missing = AppPath::with("definitely_missing_metadata.bin");
+
+    for err in [
+        missing.metadata().unwrap_err(),
+        missing.len().unwrap_err(),
+        missing.modified().unwrap_err(),
+    ] {
+        assert_eq!(err.io_kind(), Some(std::io::ErrorKind::NotFound));
+        assert!(err.to_string().contains("definitely_missing_metadata.bin"));
+    }
+}
+
+#[test]
+fn test_touch_creates_new_file_with_parents() {
+    let temp_dir = env::temp_dir().join("app_path_test_touch_new");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    // Parent directories do not exist yet
+    let marker = AppPath::with(temp_dir.join("state/locks/app.lock"));
+    marker.touch().unwrap();
+    assert!(marker.is_file());
+    assert_eq!(marker.len().unwrap(), 0);
+
+    // Touching again is fine
+    marker.touch().unwrap();
+    assert_eq!(marker.len().unwrap(), 0);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_touch_existing_file_preserves_content() {
+    let temp_dir = env::temp_dir().join("app_path_test_touch_existing");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = AppPath::with(temp_dir.join("seen.txt"));
+    fs::write(&file, "keep me").unwrap();
+    file.touch().unwrap();
+    assert_eq!(fs::read_to_string(&file).unwrap(), "keep me");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_touch_refreshes_modified_time() {
+    use std::time::{Duration, SystemTime};
+
+    let temp_dir = env::temp_dir().join("app_path_test_touch_mtime");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = AppPath::with(temp_dir.join("stamp.txt"));
+    fs::write(&file, "data").unwrap();
+    let past = SystemTime::now() - Duration::from_secs(3600);
+    fs::File::options()
+        .write(true)
+        .open(&file)
+        .unwrap()
+        .set_modified(past)
+        .unwrap();
+    assert!(file.modified().unwrap() < past + Duration::from_secs(1));
+
+    file.touch().unwrap();
+    assert!(file.modified().unwrap() > past + Duration::from_secs(1800));
+    assert_eq!(fs::read_to_string(&file).unwrap(), "data");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_touch_on_directory_fails() {
+    let temp_dir = env::temp_dir().join("app_path_test_touch_dir");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let err = AppPath::with(&temp_dir).touch().unwrap_err();
+    assert!(matches!(err, AppPathError::IoError(_)));
+    assert!(err.to_string().contains("app_path_test_touch_dir"));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_exists_as_file_and_dir() {
+    let temp_dir = env::temp_dir().join("app_path_test_exists_as");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("subdir")).unwrap();
+    fs::write(temp_dir.join("file.txt"), "x").unwrap();
+
+    let file = AppPath::with(temp_dir.join("file.txt"));
+    assert!(file.exists_as_file());
+    assert!(!file.exists_as_dir());
+
+    let dir = AppPath::with(temp_dir.join("subdir"));
+    assert!(dir.exists_as_dir());
+    assert!(!dir.exists_as_file());
+
+    let missing = AppPath::with(temp_dir.join("missing"));
+    assert!(!missing.exists_as_file());
+    assert!(!missing.exists_as_dir());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_exists_as_follows_symlinks() {
+    use std::os::unix::fs::symlink;
+
+    let temp_dir = env::temp_dir().join("app_path_test_exists_as_symlink");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(temp_dir.join("real_dir")).unwrap();
+    fs::write(temp_dir.join("real.txt"), "x").unwrap();
+    symlink(temp_dir.join("real.txt"), temp_dir.join("file_link")).unwrap();
+    symlink(temp_dir.join("real_dir"), temp_dir.join("dir_link")).unwrap();
+    symlink(temp_dir.join("gone"), temp_dir.join("dangling")).unwrap();
+
+    assert!(AppPath::with(temp_dir.join("file_link")).exists_as_file());
+    assert!(AppPath::with(temp_dir.join("dir_link")).exists_as_dir());
+
+    let dangling = AppPath::with(temp_dir.join("dangling"));
+    assert!(!dangling.exists_as_file());
+    assert!(!dangling.exists_as_dir());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_is_executable() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = env::temp_dir().join("app_path_test_is_executable");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let script = temp_dir.join("run.sh");
+    fs::write(&script, "#!/bin/sh\n").unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    assert!(AppPath::with(&script).is_executable());
+
+    // Any single execute bit counts
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o601)).unwrap();
+    assert!(AppPath::with(&script).is_executable());
+
+    let plain = temp_dir.join("notes.txt");
+    fs::write(&plain, "x").unwrap();
+    fs::set_permissions(&plain, fs::Permissions::from_mode(0o644)).unwrap();
+    assert!(!AppPath::with(&plain).is_executable());
+
+    assert!(!AppPath::with(temp_dir.join("missing")).is_executable());
+    assert!(!AppPath::with(&temp_dir).is_executable());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_set_permissions_mode() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = env::temp_dir().join("app_path_test_set_permissions");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let secrets = AppPath::with(temp_dir.join("secrets.key"));
+    secrets.write("token").unwrap();
+    secrets
+        .set_permissions(fs::Permissions::from_mode(0o600))
+        .unwrap();
+    assert_eq!(
+        secrets.metadata().unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[cfg(unix)]
+#[test]
+fn test_set_readonly_false_restores_owner_write_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let temp_dir = env::temp_dir().join("app_path_test_set_readonly_owner");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let secrets = AppPath::with(temp_dir.join("secrets.key"));
+    secrets.write("token").unwrap();
+    secrets
+        .set_permissions(fs::Permissions::from_mode(0o400))
+        .unwrap();
+
+    secrets.set_readonly(false).unwrap();
+    assert_eq!(
+        secrets.metadata().unwrap().permissions().mode() & 0o777,
+        0o600
+    );
+
+    secrets.set_readonly(true).unwrap();
+    assert_eq!(
+        secrets.metadata().unwrap().permissions().mode() & 0o777,
+        0o400
+    );
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_set_readonly_toggle() {
+    let temp_dir = env::temp_dir().join("app_path_test_set_readonly");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let file = AppPath::with(temp_dir.join("license.txt"));
+    file.write("read me").unwrap();
+
+    file.set_readonly(true).unwrap();
+    assert!(file.metadata().unwrap().permissions().readonly());
+
+    file.set_readonly(false).unwrap();
+    assert!(!file.metadata().unwrap().permissions().readonly());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_set_permissions_missing_path_names_path() {
+    let missing = AppPath::with(env::temp_dir().join("app_path_test_set_permissions_missing"));
+
+    let err = missing.set_readonly(true).unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::NotFound));
+    assert!(err
+        .to_string()
+        .contains("app_path_test_set_permissions_missing"));
+
+    let permissions = fs::metadata(env::temp_dir()).unwrap().permissions();
+    let err = missing.set_permissions(permissions).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("app_path_test_set_permissions_missing"));
+}
+
+#[test]
+fn test_open_creating_append_makes_parents() {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    let temp_dir = env::temp_dir().join("app_path_test_open_append");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let log = AppPath::with(temp_dir.join("logs/app.log"));
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+
+    write!(log.open_creating(&options).unwrap(), "one").unwrap();
+    write!(log.open_creating(&options).unwrap(), "two").unwrap();
+    assert_eq!(log.read_to_string().unwrap(), "onetwo");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_open_creating_create_new_twice_fails() {
+    use std::fs::OpenOptions;
+
+    let temp_dir = env::temp_dir().join("app_path_test_open_create_new");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let lock = AppPath::with(temp_dir.join("run/app.lock"));
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+
+    lock.open_creating(&options).unwrap();
+    let err = lock.open_creating(&options).unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::AlreadyExists));
+    assert!(err.to_string().contains("app.lock"));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_open_never_creates_directories() {
+    use std::fs::OpenOptions;
+
+    let temp_dir = env::temp_dir().join("app_path_test_open_read_missing");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let missing = AppPath::with(temp_dir.join("a/b/missing.txt"));
+    let err = missing.open(OpenOptions::new().read(true)).unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::NotFound));
+    assert!(err.to_string().contains("missing.txt"));
+    assert!(!temp_dir.join("a").exists());
+
+    let err = missing
+        .open(OpenOptions::new().write(true).create(true))
+        .unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::NotFound));
+    assert!(!temp_dir.join("a").exists());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_create_temp_in_parent_creates_empty_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_create_temp_in_parent");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let target = AppPath::with(temp_dir.join("nested/users.db"));
+    let first = target.create_temp_in_parent().unwrap();
+    let second = target.create_temp_in_parent().unwrap();
+
+    assert_ne!(first, second);
+    for temp in [&first, &second] {
+        assert_eq!(temp.parent(), target.parent());
+        assert!(temp.is_file());
+        assert_eq!(temp.len().unwrap(), 0);
+    }
+    assert!(!target.exists());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_append_writer_writes_formatted_lines() {
+    use std::io::Write;
+
+    let temp_dir = env::temp_dir().join("app_path_test_append_writer");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    // Parent directories do not exist yet
+    let log = AppPath::with(temp_dir.join("logs/app.log"));
+    {
+        let mut writer = log.append_writer().unwrap();
+        let (line, user) = (1, "alice");
+        writeln!(writer, "line {line}").unwrap();
+        writeln!(writer, "user={user} action=login").unwrap();
+        writer.flush().unwrap();
+    }
+
+    // A second writer appends instead of truncating
+    {
+        let mut writer = log.append_writer().unwrap();
+        writeln!(writer, "line 3").unwrap();
+    }
+
+    let content = fs::read_to_string(&log).unwrap();
+    assert_eq!(content, "line 1\nuser=alice action=login\nline 3\n");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_append_writer_on_directory_fails() {
+    let temp_dir = env::temp_dir().join("app_path_test_append_writer_dir");
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let result = AppPath::with(&temp_dir).append_writer();
+    assert!(matches!(result, Err(AppPathError::IoError(_))));
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_exists_with_any_extension() {
+    let temp_dir = env::temp_dir().join("app_path_test_any_extension");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let config = AppPath::with(temp_dir.join("config"));
+    let formats = ["toml", "json", "yaml"];
+
+    // Nothing exists yet
+    assert_eq!(config.exists_with_any_extension(&formats), None);
+
+    // Only the JSON variant exists
+    fs::write(temp_dir.join("config.json"), "{}").unwrap();
+    assert_eq!(
+        config.exists_with_any_extension(&formats),
+        Some(AppPath::with(temp_dir.join("config.json")))
+    );
+
+    // Both exist - the first extension in order wins
+    fs::write(temp_dir.join("config.toml"), "").unwrap();
+    assert_eq!(
+        config.exists_with_any_extension(&formats),
+        Some(AppPath::with(temp_dir.join("config.toml")))
+    );
+    assert_eq!(
+        config.exists_with_any_extension(&[".json", ".toml"]),
+        Some(AppPath::with(temp_dir.join("config.json")))
+    );
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_exists_with_any_extension_dotted_stem() {
+    let temp_dir = env::temp_dir().join("app_path_test_any_extension_dotted");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    // The extension is appended, not substituted for the last one
+    fs::write(temp_dir.join("app.settings.toml"), "").unwrap();
+    fs::write(temp_dir.join("app.toml"), "").unwrap();
+    assert_eq!(
+        AppPath::with(temp_dir.join("app.settings")).exists_with_any_extension(&["toml"]),
+        Some(AppPath::with(temp_dir.join("app.settings.toml")))
+    );
+
+    fs::write(temp_dir.join("archive.tar.gz"), "").unwrap();
+    assert_eq!(
+        AppPath::with(temp_dir.join("archive.tar")).exists_with_any_extension(&[".gz"]),
+        Some(AppPath::with(temp_dir.join("archive.tar.gz")))
+    );
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_sequence() {
+    let temp_dir = env::temp_dir().join("app_path_test_counter");
+    let _ = fs::remove_dir_all(&temp_dir);
+
+    let counter = AppPath::with(temp_dir.join("state/downloads.seq"));
+    assert_eq!(counter.increment_counter().unwrap(), 1);
+    assert_eq!(counter.increment_counter().unwrap(), 2);
+    assert_eq!(counter.increment_counter().unwrap(), 3);
+    assert_eq!(fs::read_to_string(&counter).unwrap(), "3");
+
+    // Continues from an existing value and leaves no lock or temp files behind
+    fs::write(&counter, "41\n").unwrap();
+    assert_eq!(counter.increment_counter().unwrap(), 42);
+    assert_eq!(fs::read_dir(temp_dir.join("state")).unwrap().count(), 1);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_invalid_content() {
+    let temp_dir = env::temp_dir().join("app_path_test_counter_invalid");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let counter = AppPath::with(temp_dir.join("bad.seq"));
+    fs::write(&counter, "not a number").unwrap();
+
+    match counter.increment_counter() {
+        Err(AppPathError::IoError(io_err)) => {
+            assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidData);
+        }
+        other => panic!("Expected InvalidData IoError, got: {other:?}"),
+    }
+    // The lock is released even on failure
+    assert!(!temp_dir.join("bad.seq.lock").exists());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_breaks_stale_lock() {
+    use std::time::{Duration, Instant, SystemTime};
+
+    let temp_dir = env::temp_dir().join("app_path_test_counter_stale_lock");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    let counter = AppPath::with(temp_dir.join("jobs.seq"));
+    let lock = temp_dir.join("jobs.seq.lock");
+
+    // A lock left behind by a holder that died an hour ago
+    fs::File::create(&lock)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(3600))
+        .unwrap();
+
+    let started = Instant::now();
+    assert_eq!(counter.increment_counter().unwrap(), 1);
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(!lock.exists());
+    assert_eq!(fs::read_dir(&temp_dir).unwrap().count(), 1);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_breaks_stale_lock_with_abandoned_guard() {
+    use std::time::{Duration, SystemTime};
+
+    let temp_dir = env::temp_dir().join("app_path_test_counter_stale_guard");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    let counter = AppPath::with(temp_dir.join("jobs.seq"));
+
+    // A waiter died while breaking a lock whose holder had died too
+    for name in ["jobs.seq.lock", "jobs.seq.lock.break"] {
+        fs::File::create(temp_dir.join(name))
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+    }
+
+    assert_eq!(counter.increment_counter().unwrap(), 1);
+    assert_eq!(fs::read_dir(&temp_dir).unwrap().count(), 1);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_keeps_recent_lock() {
+    use std::time::{Duration, SystemTime};
+
+    let temp_dir = env::temp_dir().join("app_path_test_counter_recent_lock");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    let counter = AppPath::with(temp_dir.join("jobs.seq"));
+    let lock = temp_dir.join("jobs.seq.lock");
+
+    // Older than the wait timeout, but too recent to be considered abandoned
+    fs::File::create(&lock)
+        .unwrap()
+        .set_modified(SystemTime::now() - Duration::from_secs(60))
+        .unwrap();
+
+    let err = counter.increment_counter().unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::TimedOut));
+    assert!(lock.exists());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_increment_counter_concurrent_no_lost_updates() {
+    use std::collections::HashSet;
+
+    let temp_dir = env::temp_dir().join("app_path_test_counter_concurrent");
+    let _ = fs::remove_dir_all(&temp_dir);
+    let counter = AppPath::with(temp_dir.join("shared.seq"));
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let counter = counter.clone();
+            std::thread::spawn(move || {
+                (0..25)
+                    .map(|_| counter.increment_counter().unwrap())
+                    .collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let values: HashSet<u64> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(values.len(), 200);
+    assert_eq!(values.iter().max(), Some(&200));
+    assert_eq!(fs::read_to_string(&counter).unwrap(), "200");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_first_run_true_once() {
+    let temp_dir = env::temp_dir().join("app_path_test_first_run");
+    let _ = fs::remove_dir_all(&temp_dir);
+    let marker = temp_dir.join("state/first.marker");
+    let marker = marker.to_str().unwrap();
+
+    assert!(AppPath::first_run(marker).unwrap());
+    assert!(AppPath::with(marker).is_file());
+    assert!(!AppPath::first_run(marker).unwrap());
+    assert!(!AppPath::first_run(marker).unwrap());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_first_run_concurrent_single_winner() {
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    let temp_dir = env::temp_dir().join("app_path_test_first_run_concurrent");
+    let _ = fs::remove_dir_all(&temp_dir);
+    let marker = temp_dir.join("first.marker").to_str().unwrap().to_string();
+
+    let threads = 8;
+    let barrier = Arc::new(Barrier::new(threads));
+    let handles: Vec<_> = (0..threads)
+        .map(|_| {
+            let barrier = Arc::clone(&barrier);
+            let marker = marker.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                AppPath::first_run(&marker).unwrap()
+            })
+        })
+        .collect();
+
+    let winners = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .filter(|&first| first)
+        .count();
+    assert_eq!(winners, 1);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_first_run_error_when_parent_is_file() {
+    let temp_dir = env::temp_dir().join("app_path_test_first_run_error");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+    fs::write(temp_dir.join("blocker"), "file").unwrap();
+
+    let marker = temp_dir.join("blocker/first.marker");
+    assert!(AppPath::first_run(marker.to_str().unwrap()).is_err());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_rotate_several_times() {
+    let temp_dir = env::temp_dir().join("app_path_test_rotate");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let log = AppPath::with(temp_dir.join("app.log"));
+    for day in 1..=5 {
+        fs::write(&log, format!("day {day}")).unwrap();
+        log.rotate(3).unwrap();
+        assert!(!log.exists());
+    }
+
+    let mut names: Vec<String> = fs::read_dir(&temp_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(names, ["app.1.log", "app.2.log", "app.3.log"]);
+
+    assert_eq!(fs::read_to_string(log.rotated(1)).unwrap(), "day 5");
+    assert_eq!(fs::read_to_string(log.rotated(2)).unwrap(), "day 4");
+    assert_eq!(fs::read_to_string(log.rotated(3)).unwrap(), "day 3");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_rotate_keep_zero_and_missing_files() {
+    let temp_dir = env::temp_dir().join("app_path_test_rotate_edge");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let log = AppPath::with(temp_dir.join("app.log"));
+
+    // Nothing to rotate is not an error
+    log.rotate(2).unwrap();
+
+    fs::write(&log, "current").unwrap();
+    log.rotate(0).unwrap();
+    assert!(!log.exists());
+    assert!(!log.rotated(1).exists());
+
+    // Gaps in the chain are skipped
+    fs::write(log.rotated(2), "old").unwrap();
+    fs::write(&log, "new").unwrap();
+    log.rotate(3).unwrap();
+    assert_eq!(fs::read_to_string(log.rotated(1)).unwrap(), "new");
+    assert!(!log.rotated(2).exists());
+    assert_eq!(fs::read_to_string(log.rotated(3)).unwrap(), "old");
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
@@ -0,0 +1,42 @@
+use crate::app_path::introspection::install_id_for;
+use crate::AppPath;
+use std::env;
+use std::fs;
+
+#[test]
+fn test_install_id_is_deterministic() {
+    let first = AppPath::install_id().unwrap();
+    let second = AppPath::install_id().unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(first.len(), 16);
+    assert!(first.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn test_install_id_differs_between_install_locations() {
+    let root = env::temp_dir().join("app_path_test_install_id");
+    let _ = fs::remove_dir_all(&root);
+    let exe_a = root.join("install_a/myapp");
+    let exe_b = root.join("install_b/myapp");
+    for exe in [&exe_a, &exe_b] {
+        fs::create_dir_all(exe.parent().unwrap()).unwrap();
+        fs::write(exe, "binary").unwrap();
+    }
+
+    let id_a = install_id_for(&exe_a).unwrap();
+    let id_b = install_id_for(&exe_b).unwrap();
+    assert_ne!(id_a, id_b);
+
+    // Different spellings of the same executable produce the same id
+    let respelled = root.join("install_b/../install_a/myapp");
+    assert_eq!(install_id_for(&respelled).unwrap(), id_a);
+
+    fs::remove_dir_all(&root).ok();
+}
+
+#[test]
+fn test_install_id_missing_executable() {
+    let missing = env::temp_dir().join("app_path_test_install_id_missing/myapp");
+    assert!(install_id_for(&missing).is_err());
+}
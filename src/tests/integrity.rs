@@ -0,0 +1,71 @@
+use crate::{AppPath, AppPathError};
+use std::env;
+use std::fs;
+
+// SHA-256 of "abc"
+const ABC_SHA256: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[test]
+fn test_verify_sha256_sidecar_match() {
+    let temp_dir = env::temp_dir().join("app_path_test_sha256_match");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let asset = AppPath::with(temp_dir.join("data.bin"));
+    fs::write(&asset, b"abc").unwrap();
+
+    // Bare digest
+    fs::write(temp_dir.join("data.bin.sha256"), ABC_SHA256).unwrap();
+    assert!(asset.verify_sha256_sidecar().unwrap());
+
+    // sha256sum format, uppercase, trailing newline
+    let sidecar = format!("{}  data.bin\n", ABC_SHA256.to_uppercase());
+    fs::write(temp_dir.join("data.bin.sha256"), sidecar).unwrap();
+    assert!(asset.verify_sha256_sidecar().unwrap());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_verify_sha256_sidecar_mismatch() {
+    let temp_dir = env::temp_dir().join("app_path_test_sha256_mismatch");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let asset = AppPath::with(temp_dir.join("data.bin"));
+    fs::write(&asset, b"tampered").unwrap();
+    fs::write(temp_dir.join("data.bin.sha256"), ABC_SHA256).unwrap();
+
+    assert!(!asset.verify_sha256_sidecar().unwrap());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_verify_sha256_sidecar_missing_or_malformed() {
+    let temp_dir = env::temp_dir().join("app_path_test_sha256_errors");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let asset = AppPath::with(temp_dir.join("data.bin"));
+    fs::write(&asset, b"abc").unwrap();
+
+    match asset.verify_sha256_sidecar() {
+        Err(AppPathError::IoError(io_err)) => {
+            assert_eq!(io_err.kind(), std::io::ErrorKind::NotFound);
+            assert!(io_err.to_string().contains("data.bin.sha256"));
+        }
+        other => panic!("Expected IoError for missing sidecar, got: {other:?}"),
+    }
+
+    fs::write(temp_dir.join("data.bin.sha256"), "not-a-digest").unwrap();
+    match asset.verify_sha256_sidecar() {
+        Err(AppPathError::IoError(io_err)) => {
+            assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidData);
+            assert!(io_err.to_string().contains("malformed"));
+        }
+        other => panic!("Expected IoError for malformed sidecar, got: {other:?}"),
+    }
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
@@ -0,0 +1,46 @@
+use crate::{AppPath, AppPathIteratorExt};
+
+#[test]
+fn test_group_by_parent_buckets_by_directory() {
+    let paths = vec![
+        AppPath::with("data/users/1.json"),
+        AppPath::with("logs/app.log"),
+        AppPath::with("data/users/2.json"),
+        AppPath::with("config.toml"),
+        AppPath::with("logs/error.log"),
+    ];
+
+    let grouped = paths.into_iter().group_by_parent();
+
+    assert_eq!(grouped.len(), 3);
+    assert_eq!(
+        grouped[&AppPath::with("data/users")],
+        vec![
+            AppPath::with("data/users/1.json"),
+            AppPath::with("data/users/2.json")
+        ]
+    );
+    assert_eq!(
+        grouped[&AppPath::with("logs")],
+        vec![
+            AppPath::with("logs/app.log"),
+            AppPath::with("logs/error.log")
+        ]
+    );
+    assert_eq!(grouped[&AppPath::new()], vec![AppPath::with("config.toml")]);
+}
+
+#[test]
+fn test_group_by_parent_root_without_parent() {
+    let root = if cfg!(windows) { r"C:\" } else { "/" };
+    let grouped = vec![AppPath::with(root)].into_iter().group_by_parent();
+
+    // A root has no parent, so it is grouped under itself
+    assert_eq!(grouped[&AppPath::with(root)], vec![AppPath::with(root)]);
+}
+
+#[test]
+fn test_group_by_parent_empty_iterator() {
+    let grouped = Vec::<AppPath>::new().into_iter().group_by_parent();
+    assert!(grouped.is_empty());
+}
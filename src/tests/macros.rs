@@ -100,6 +100,62 @@ fn test_app_path_macro_with_fn() {
     assert_eq!(&*complex_config, &expected_complex);
 }
 
+#[test]
+fn test_app_path_macro_with_base() {
+    let plugin_root = env::temp_dir().join("macro_plugin_root");
+
+    let settings = app_path!(base = &plugin_root, "settings.toml");
+    assert_eq!(&*settings, plugin_root.join("settings.toml").as_path());
+    assert_eq!(settings, AppPath::with_base(&plugin_root, "settings.toml"));
+
+    // Composes with other AppPath values as the base
+    let base = app_path!("plugins/audio");
+    let nested = app_path!(base = &base, "presets/default.json");
+    assert_eq!(nested, app_path!("plugins/audio/presets/default.json"));
+
+    // Absolute paths still replace the base
+    let absolute = env::temp_dir().join("macro_base_absolute.toml");
+    assert_eq!(
+        &*app_path!(base = &plugin_root, &absolute),
+        absolute.as_path()
+    );
+}
+
+#[test]
+fn test_app_path_macro_segments_two() {
+    let two = app_path!["data", "users.db"];
+    assert_eq!(two, app_path!("data").join("users.db"));
+
+    // Trailing comma is accepted
+    let trailing = app_path!["data", "users.db",];
+    assert_eq!(trailing, two);
+}
+
+#[test]
+fn test_app_path_macro_segments_many() {
+    let many = app_path!["a", "b", "c", "d", "e.txt"];
+    assert_eq!(many, AppPath::with("a/b/c/d/e.txt"));
+
+    // Any AsRef<Path> works as a segment
+    let dir = PathBuf::from("cache");
+    let name = String::from("entry.bin");
+    let mixed_types = app_path![dir, std::path::Path::new("v2"), &name];
+    assert_eq!(mixed_types, AppPath::with("cache/v2/entry.bin"));
+}
+
+#[test]
+fn test_app_path_macro_segments_with_format() {
+    let id = 42;
+    let year = 2024;
+    let log = app_path!["data", format!("{year}"), format!("user-{id}.log")];
+    assert_eq!(
+        log,
+        app_path!("data")
+            .join("2024")
+            .join(format!("user-{id}.log"))
+    );
+}
+
 // === try_app_path! Macro Tests ===
 
 #[test]
@@ -204,6 +260,25 @@ fn test_try_app_path_macro_with_fn() {
     assert_eq!(&*complex_config, &expected_complex);
 }
 
+#[test]
+fn test_try_app_path_macro_with_base() {
+    let plugin_root = env::temp_dir().join("try_macro_plugin_root");
+
+    let settings = try_app_path!(base = &plugin_root, "settings.toml").unwrap();
+    assert_eq!(&*settings, plugin_root.join("settings.toml").as_path());
+
+    // Should be consistent with panicking version
+    assert_eq!(settings, app_path!(base = &plugin_root, "settings.toml"));
+}
+
+#[test]
+fn test_try_app_path_macro_segments() {
+    let id = 7;
+    let log = try_app_path!["data", "2024", format!("user-{id}.log")].unwrap();
+    assert_eq!(log, app_path!["data", "2024", format!("user-{id}.log")]);
+    assert!(log.ends_with("data/2024/user-7.log") || log.ends_with("data\\2024\\user-7.log"));
+}
+
 #[test]
 fn test_try_app_path_macro_returns_result() {
     // Test that the macro returns a Result type
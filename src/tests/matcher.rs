@@ -0,0 +1,133 @@
+use crate::{AppPath, PathMatcher};
+use std::env;
+use std::fs;
+
+#[test]
+fn test_matcher_star() {
+    let toml = PathMatcher::new("*.toml");
+    assert!(toml.matches(&AppPath::with("config.toml")));
+    assert!(toml.matches(&AppPath::with(".toml")));
+    assert!(!toml.matches(&AppPath::with("config.toml.bak")));
+    // `*` never spans components
+    assert!(!toml.matches(&AppPath::with("config/app.toml")));
+
+    let infix = PathMatcher::new("app-*-v*.log");
+    assert!(infix.matches(&AppPath::with("app-server-v2.log")));
+    assert!(!infix.matches(&AppPath::with("app-server.log")));
+}
+
+#[test]
+fn test_matcher_question_mark() {
+    let matcher = PathMatcher::new("log?.txt");
+    assert!(matcher.matches(&AppPath::with("log1.txt")));
+    assert!(!matcher.matches(&AppPath::with("log.txt")));
+    assert!(!matcher.matches(&AppPath::with("log12.txt")));
+}
+
+#[test]
+fn test_matcher_double_star() {
+    let matcher = PathMatcher::new("**/*.json");
+    assert!(matcher.matches(&AppPath::with("users.json")));
+    assert!(matcher.matches(&AppPath::with("data/users/1.json")));
+    assert!(!matcher.matches(&AppPath::with("data/users/1.yaml")));
+
+    let middle = PathMatcher::new("data/**/config.toml");
+    assert!(middle.matches(&AppPath::with("data/config.toml")));
+    assert!(middle.matches(&AppPath::with("data/a/b/c/config.toml")));
+    assert!(!middle.matches(&AppPath::with("other/a/config.toml")));
+
+    let everything = PathMatcher::new("**");
+    assert!(everything.matches(&AppPath::with("any/depth/file")));
+}
+
+#[test]
+fn test_matcher_collapses_consecutive_double_stars() {
+    assert_eq!(
+        PathMatcher::new("data/**/**/**/config.toml"),
+        PathMatcher::new("data/**/config.toml")
+    );
+
+    let trailing = PathMatcher::new("data/**/**");
+    assert!(trailing.matches(&AppPath::with("data")));
+    assert!(trailing.matches(&AppPath::with("data/a/b")));
+}
+
+#[test]
+fn test_matcher_many_double_stars_is_not_exponential() {
+    // Backtracking over every way the `**`s could split 60 components never finishes
+    let matcher = PathMatcher::new("**/a/**/a/**/a/**/a/**/a/**/a/**/b");
+    let deep = vec!["a"; 60].join("/");
+    assert!(!matcher.matches(&AppPath::with(&deep)));
+    assert!(matcher.matches(&AppPath::with(format!("{deep}/b"))));
+}
+
+#[test]
+fn test_matcher_directory_spanning() {
+    let matcher = PathMatcher::new("data/*/config.*");
+    assert!(matcher.matches(&AppPath::with("data/plugin/config.toml")));
+    assert!(matcher.matches(&AppPath::with("data/./plugin/../other/config.ini")));
+    assert!(!matcher.matches(&AppPath::with("data/config.toml")));
+    assert!(!matcher.matches(&AppPath::with("data/a/b/config.toml")));
+}
+
+#[test]
+fn test_matcher_outside_base_never_matches() {
+    let outside = if cfg!(windows) {
+        r"C:\temp\config.toml"
+    } else {
+        "/tmp/config.toml"
+    };
+    assert!(!PathMatcher::new("**").matches(&AppPath::with(outside)));
+    assert!(!PathMatcher::new("*.toml").matches(&AppPath::with("../config.toml")));
+}
+
+#[test]
+fn test_glob_over_temp_tree() {
+    let temp_dir = env::temp_dir().join("app_path_test_glob");
+    let _ = fs::remove_dir_all(&temp_dir);
+    for dir in ["data/a", "data/b/deep", "logs"] {
+        fs::create_dir_all(temp_dir.join(dir)).unwrap();
+    }
+    for file in [
+        "app.toml",
+        "data/a/config.toml",
+        "data/b/config.ini",
+        "data/b/deep/config.toml",
+        "logs/app1.log",
+        "logs/app22.log",
+    ] {
+        fs::write(temp_dir.join(file), "").unwrap();
+    }
+
+    let root = AppPath::with(&temp_dir);
+    let relative = |paths: Vec<AppPath>| -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| {
+                let rel = path.strip_prefix(&temp_dir).unwrap();
+                rel.to_string_lossy().replace('\\', "/")
+            })
+            .collect()
+    };
+
+    assert_eq!(relative(root.glob("*.toml").unwrap()), ["app.toml"]);
+    assert_eq!(
+        relative(root.glob("data/*/config.*").unwrap()),
+        ["data/a/config.toml", "data/b/config.ini"]
+    );
+    assert_eq!(
+        relative(root.glob("**/config.toml").unwrap()),
+        ["data/a/config.toml", "data/b/deep/config.toml"]
+    );
+    assert_eq!(
+        relative(root.glob("logs/app?.log").unwrap()),
+        ["logs/app1.log"]
+    );
+    // Directories match too
+    assert_eq!(relative(root.glob("data/*").unwrap()), ["data/a", "data/b"]);
+    assert!(root.glob("*.missing").unwrap().is_empty());
+
+    assert!(AppPath::with(temp_dir.join("nope")).glob("*").is_err());
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
@@ -0,0 +1,29 @@
+use crate::AppPath;
+use std::env;
+use std::fs;
+
+#[test]
+fn test_mmap_matches_file_contents() {
+    let temp_dir = env::temp_dir().join("app_path_test_mmap");
+    let _ = fs::remove_dir_all(&temp_dir);
+    fs::create_dir_all(&temp_dir).unwrap();
+
+    let asset = AppPath::with(temp_dir.join("index.bin"));
+    let contents: Vec<u8> = (0..=255u8).cycle().take(64 * 1024).collect();
+    fs::write(&asset, &contents).unwrap();
+
+    let map = asset.mmap().unwrap();
+    assert_eq!(&map[..], fs::read(&asset).unwrap().as_slice());
+    drop(map);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_mmap_missing_file_error() {
+    let missing = AppPath::with(env::temp_dir().join("app_path_test_mmap_missing.bin"));
+    let _ = fs::remove_file(&missing);
+
+    let err = missing.mmap().unwrap_err();
+    assert!(err.to_string().contains("app_path_test_mmap_missing.bin"));
+}
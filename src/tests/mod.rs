@@ -1,11 +1,39 @@
 // Test modules for app-path
 // Organized by functionality for better maintainability
 
+#[cfg(feature = "tokio")]
+mod async_override;
+mod base_relative;
 mod basic;
+mod builder;
+#[cfg(feature = "cache")]
+mod cache;
+mod compiled_base;
 mod constructors;
+mod deployment;
 mod directory_creation;
+mod env_overrides;
 mod error_handling;
+mod file_ops;
+mod install_id;
+#[cfg(feature = "sha2")]
+mod integrity;
+mod iter;
 mod macros;
+mod matcher;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod overrides;
 mod path_manipulation;
+mod policy;
+mod relative;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod support;
+#[cfg(feature = "testing")]
+mod testing;
 mod traits;
+#[cfg(feature = "typed-path")]
+mod typed_path;
+#[cfg(feature = "camino")]
+mod utf8;
@@ -1,4 +1,4 @@
-use crate::{app_path, try_app_path};
+use crate::{app_path, try_app_path, AppPath, AppPathError};
 use std::env;
 use std::path::PathBuf;
 
@@ -31,23 +31,58 @@ fn test_env_override_with_nonexistent_var() {
 fn test_env_override_empty_value() {
     env::set_var("EMPTY_ENV_VAR", "");
 
+    // An exported but empty variable falls back to the default
     let config = app_path!("default.toml", env = "EMPTY_ENV_VAR");
-    // Empty env var creates AppPath with empty string, which resolves to the
-    // directory where the test binary is executed from (target/debug/deps/)
-    let expected_path = config.to_path_buf();
-    assert!(expected_path.to_string_lossy().contains("target"));
-
-    // Verify it's a directory path (ends with separator)
-    assert!(
-        config.is_dir()
-            || config
-                .to_string_lossy()
-                .ends_with(std::path::MAIN_SEPARATOR)
-    );
+    assert_eq!(config, AppPath::with("default.toml"));
+
+    let fallible = try_app_path!("default.toml", env = "EMPTY_ENV_VAR").unwrap();
+    assert_eq!(fallible, config);
 
     env::remove_var("EMPTY_ENV_VAR");
 }
 
+#[test]
+fn test_env_override_whitespace_value() {
+    env::set_var("WHITESPACE_ENV_VAR", " \t ");
+
+    let config = app_path!("default.toml", env = "WHITESPACE_ENV_VAR");
+    assert_eq!(config, AppPath::with("default.toml"));
+
+    let fallible = try_app_path!("default.toml", env = "WHITESPACE_ENV_VAR").unwrap();
+    assert_eq!(fallible, config);
+
+    env::remove_var("WHITESPACE_ENV_VAR");
+}
+
+#[test]
+fn test_blank_direct_override_falls_back() {
+    assert_eq!(
+        AppPath::with_override("default.toml", Some("")),
+        AppPath::with("default.toml")
+    );
+    assert_eq!(
+        AppPath::try_with_override("default.toml", Some("   ")).unwrap(),
+        AppPath::with("default.toml")
+    );
+}
+
+#[test]
+fn test_override_allow_empty_keeps_empty_value() {
+    // Opt-out: an empty override resolves to the base directory itself
+    assert_eq!(
+        AppPath::with_override_allow_empty("default.toml", Some("")),
+        AppPath::new()
+    );
+    assert_eq!(
+        AppPath::try_with_override_allow_empty("default.toml", Some("")).unwrap(),
+        AppPath::new()
+    );
+    assert_eq!(
+        AppPath::with_override_allow_empty("default.toml", None::<&str>),
+        AppPath::with("default.toml")
+    );
+}
+
 #[test]
 fn test_env_override_relative_path() {
     env::set_var("RELATIVE_PATH_VAR", "config/test.toml");
@@ -329,3 +364,270 @@ fn test_try_app_path_override_equivalence() {
     let fallible_fn = try_app_path!("test.toml", fn = || Some(test_path.clone())).unwrap();
     assert_eq!(panicking_fn, fallible_fn);
 }
+
+// === Reported Resolution Tests ===
+
+#[test]
+fn test_resolve_reported_sources() {
+    use crate::{AppPath, ResolutionSource};
+
+    let override_path = env::temp_dir().join("app_path_reported_config.toml");
+    env::set_var("APP_PATH_TEST_REPORTED_CONFIG", &override_path);
+    env::remove_var("APP_PATH_TEST_REPORTED_DATA");
+
+    let resolved = AppPath::resolve_reported(&[
+        ("config.toml", "APP_PATH_TEST_REPORTED_CONFIG"),
+        ("data", "APP_PATH_TEST_REPORTED_DATA"),
+    ]);
+
+    assert_eq!(
+        resolved,
+        vec![
+            (
+                AppPath::with(&override_path),
+                ResolutionSource::EnvOverride("APP_PATH_TEST_REPORTED_CONFIG".to_string()),
+            ),
+            (AppPath::with("data"), ResolutionSource::Default),
+        ]
+    );
+    assert!(AppPath::registered_env_overrides().contains(&"APP_PATH_TEST_REPORTED_DATA"));
+
+    env::remove_var("APP_PATH_TEST_REPORTED_CONFIG");
+}
+
+#[test]
+fn test_with_override_traced_sources() {
+    use crate::ResolutionSource;
+
+    let (path, source) = AppPath::with_override_traced("default.toml", Some("custom.toml"));
+    assert_eq!(path, AppPath::with("custom.toml"));
+    assert_eq!(source, ResolutionSource::Override);
+
+    let (path, source) = AppPath::with_override_traced("default.toml", None::<&str>);
+    assert_eq!(path, AppPath::with("default.toml"));
+    assert_eq!(source, ResolutionSource::Default);
+
+    // Blank overrides count as absent, as in `with_override`
+    let (path, source) = AppPath::with_override_traced("default.toml", Some("  "));
+    assert_eq!(path, AppPath::with("default.toml"));
+    assert_eq!(source, ResolutionSource::Default);
+}
+
+#[test]
+fn test_with_override_envs_traced_reports_winning_variable() {
+    use crate::ResolutionSource;
+
+    env::set_var("APP_PATH_TEST_TRACED_EMPTY", "");
+    env::set_var("APP_PATH_TEST_TRACED_SET", "traced.toml");
+
+    let (path, source) = AppPath::with_override_envs_traced(
+        "default.toml",
+        &[
+            "APP_PATH_TEST_TRACED_UNSET",
+            "APP_PATH_TEST_TRACED_EMPTY",
+            "APP_PATH_TEST_TRACED_SET",
+        ],
+    );
+    assert_eq!(path, AppPath::with("traced.toml"));
+    assert_eq!(
+        source,
+        ResolutionSource::EnvOverride("APP_PATH_TEST_TRACED_SET".to_string())
+    );
+
+    let (path, source) =
+        AppPath::with_override_envs_traced("default.toml", &["APP_PATH_TEST_TRACED_UNSET"]);
+    assert_eq!(path, AppPath::with("default.toml"));
+    assert_eq!(source, ResolutionSource::Default);
+
+    env::remove_var("APP_PATH_TEST_TRACED_EMPTY");
+    env::remove_var("APP_PATH_TEST_TRACED_SET");
+}
+
+#[test]
+fn test_resolve_reported_empty() {
+    assert!(crate::AppPath::resolve_reported(&[]).is_empty());
+}
+
+// === Search Path Tests ===
+
+#[test]
+fn test_from_search_path_positions() {
+    use crate::AppPath;
+    use std::fs;
+
+    let temp_dir = env::temp_dir().join("app_path_test_search_path");
+    let _ = fs::remove_dir_all(&temp_dir);
+    let dirs: Vec<PathBuf> = ["first", "second", "third"]
+        .iter()
+        .map(|name| temp_dir.join(name))
+        .collect();
+    for dir in &dirs {
+        fs::create_dir_all(dir).unwrap();
+    }
+    fs::write(dirs[1].join("shared.txt"), "second").unwrap();
+    fs::write(dirs[2].join("shared.txt"), "third").unwrap();
+    fs::write(dirs[2].join("last.txt"), "third").unwrap();
+
+    let var = "APP_PATH_TEST_SEARCH_PATH";
+    env::set_var(var, env::join_paths(&dirs).unwrap());
+
+    // Earlier directories win
+    assert_eq!(
+        AppPath::from_search_path(var, "shared.txt"),
+        Some(AppPath::with(dirs[1].join("shared.txt")))
+    );
+    assert_eq!(
+        AppPath::from_search_path(var, "last.txt"),
+        Some(AppPath::with(dirs[2].join("last.txt")))
+    );
+    assert_eq!(AppPath::from_search_path(var, "missing.txt"), None);
+
+    env::remove_var(var);
+    assert_eq!(AppPath::from_search_path(var, "shared.txt"), None);
+
+    fs::remove_dir_all(&temp_dir).ok();
+}
+
+#[test]
+fn test_from_search_path_relative_entry() {
+    use crate::AppPath;
+    use std::fs;
+
+    let relative_dir = AppPath::with("app_path_test_search_relative");
+    let _ = fs::remove_dir_all(&relative_dir);
+    relative_dir.create_dir().unwrap();
+    fs::write(relative_dir.join("plugin.cfg"), "cfg").unwrap();
+
+    let absolute_dir = env::temp_dir().join("app_path_test_search_relative_abs");
+    fs::create_dir_all(&absolute_dir).unwrap();
+
+    let var = "APP_PATH_TEST_SEARCH_RELATIVE";
+    let entries = [
+        absolute_dir.clone(),
+        PathBuf::new(),
+        PathBuf::from("app_path_test_search_relative"),
+    ];
+    env::set_var(var, env::join_paths(&entries).unwrap());
+
+    assert_eq!(
+        AppPath::from_search_path(var, "plugin.cfg"),
+        Some(relative_dir.join("plugin.cfg"))
+    );
+
+    env::remove_var(var);
+    fs::remove_dir_all(&relative_dir).ok();
+    fs::remove_dir_all(&absolute_dir).ok();
+}
+
+// === Multiple Environment Variable Override Tests ===
+
+#[test]
+fn test_override_envs_first_hit() {
+    env::set_var("TEST_ENVS_FIRST_A", "first.toml");
+    env::set_var("TEST_ENVS_FIRST_B", "second.toml");
+
+    let config =
+        AppPath::with_override_envs("default.toml", &["TEST_ENVS_FIRST_A", "TEST_ENVS_FIRST_B"]);
+    assert_eq!(config, AppPath::with("first.toml"));
+
+    env::remove_var("TEST_ENVS_FIRST_A");
+    env::remove_var("TEST_ENVS_FIRST_B");
+}
+
+#[test]
+fn test_override_envs_second_hit() {
+    env::set_var("TEST_ENVS_SECOND_B", "second.toml");
+
+    let config = AppPath::with_override_envs(
+        "default.toml",
+        &["TEST_ENVS_SECOND_A_UNSET", "TEST_ENVS_SECOND_B"],
+    );
+    assert_eq!(config, AppPath::with("second.toml"));
+
+    env::remove_var("TEST_ENVS_SECOND_B");
+}
+
+#[test]
+fn test_override_envs_all_unset() {
+    let config =
+        AppPath::with_override_envs("default.toml", &["TEST_ENVS_UNSET_A", "TEST_ENVS_UNSET_B"]);
+    assert_eq!(config, AppPath::with("default.toml"));
+
+    let no_vars = AppPath::with_override_envs("default.toml", &[]);
+    assert_eq!(no_vars, AppPath::with("default.toml"));
+}
+
+#[test]
+fn test_override_envs_empty_value_skipped() {
+    env::set_var("TEST_ENVS_EMPTY_A", "");
+    env::set_var("TEST_ENVS_EMPTY_WS", "  ");
+    env::set_var("TEST_ENVS_EMPTY_B", "second.toml");
+
+    let config =
+        AppPath::with_override_envs("default.toml", &["TEST_ENVS_EMPTY_A", "TEST_ENVS_EMPTY_B"]);
+    assert_eq!(config, AppPath::with("second.toml"));
+
+    let only_empty = AppPath::with_override_envs("default.toml", &["TEST_ENVS_EMPTY_A"]);
+    assert_eq!(only_empty, AppPath::with("default.toml"));
+
+    env::remove_var("TEST_ENVS_EMPTY_A");
+    env::remove_var("TEST_ENVS_EMPTY_WS");
+    env::remove_var("TEST_ENVS_EMPTY_B");
+}
+
+#[test]
+fn test_override_envs_macros() {
+    env::set_var("TEST_ENVS_MACRO_B", "macro.toml");
+
+    let config = app_path!(
+        "default.toml",
+        envs = ["TEST_ENVS_MACRO_A", "TEST_ENVS_MACRO_B"]
+    );
+    assert_eq!(config, AppPath::with("macro.toml"));
+
+    let fallible = try_app_path!(
+        "default.toml",
+        envs = ["TEST_ENVS_MACRO_A", "TEST_ENVS_MACRO_B",]
+    )
+    .unwrap();
+    assert_eq!(fallible, config);
+
+    let fallback = try_app_path!("default.toml", envs = ["TEST_ENVS_MACRO_A"]).unwrap();
+    assert_eq!(fallback, AppPath::with("default.toml"));
+
+    env::remove_var("TEST_ENVS_MACRO_B");
+}
+
+// === Fallible Function Override Tests ===
+
+#[test]
+fn test_override_result_fn_ok_some() {
+    let config = AppPath::try_with_override_result_fn("default.toml", || {
+        Ok::<_, std::io::Error>(Some("from_keyring.toml"))
+    })
+    .unwrap();
+    assert_eq!(config, AppPath::with("from_keyring.toml"));
+}
+
+#[test]
+fn test_override_result_fn_ok_none_falls_back() {
+    let config = AppPath::try_with_override_result_fn("default.toml", || {
+        Ok::<_, AppPathError>(None::<&str>)
+    })
+    .unwrap();
+    assert_eq!(config, AppPath::with("default.toml"));
+}
+
+#[test]
+fn test_override_result_fn_err_propagates() {
+    let result = AppPath::try_with_override_result_fn("default.toml", || {
+        Err::<Option<&str>, _>(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "keyring locked",
+        ))
+    });
+
+    let err = result.unwrap_err();
+    assert_eq!(err.io_kind(), Some(std::io::ErrorKind::PermissionDenied));
+    assert!(err.to_string().contains("keyring locked"));
+}
@@ -60,6 +60,23 @@ fn test_parent() {
 
 // === Path Joining and Manipulation ===
 
+#[test]
+fn test_ancestor_app_paths_matches_std_ancestors() {
+    let path = app_path!("data/users/2024/profile.json");
+
+    let yielded: Vec<PathBuf> = path
+        .ancestor_app_paths()
+        .map(AppPath::into_path_buf)
+        .collect();
+    let expected: Vec<PathBuf> = path.ancestors().map(Path::to_path_buf).collect();
+    assert_eq!(yielded, expected);
+
+    assert_eq!(path.ancestor_app_paths().next(), Some(path.clone()));
+    assert_eq!(path.ancestor_app_paths().nth(4), Some(AppPath::new()));
+    let root = path.ancestor_app_paths().last().unwrap();
+    assert!(root.parent().is_none());
+}
+
 #[test]
 fn test_join() {
     let base = app_path!("config");
@@ -74,10 +91,149 @@ fn test_join() {
     );
 }
 
+#[test]
+fn test_join_all_empty_returns_copy() {
+    let base = app_path!("data");
+    let joined = base.join_all(std::iter::empty::<&str>());
+    assert_eq!(joined, base);
+}
+
+#[test]
+fn test_join_all_several_segments() {
+    let route = vec![
+        "users".to_string(),
+        "42".to_string(),
+        "avatar.png".to_string(),
+    ];
+    let joined = app_path!("static").join_all(&route);
+    assert_eq!(joined, app_path!("static/users/42/avatar.png"));
+
+    let folded = route
+        .iter()
+        .fold(app_path!("static"), |path, segment| path.join(segment));
+    assert_eq!(joined, folded);
+}
+
+#[test]
+fn test_join_all_absolute_segment_mid_stream() {
+    let absolute = std::env::temp_dir().join("join_all_absolute");
+    let segments = [
+        PathBuf::from("ignored"),
+        absolute.clone(),
+        PathBuf::from("nested.txt"),
+    ];
+    let joined = app_path!("data").join_all(segments);
+    assert_eq!(&*joined, absolute.join("nested.txt").as_path());
+}
+
+#[test]
+fn test_join_checked_normal_segment() {
+    let uploads = app_path!("uploads");
+    assert_eq!(
+        uploads.join_checked("2024/photo.jpg").unwrap(),
+        uploads.join("2024/photo.jpg")
+    );
+    assert_eq!(
+        uploads.join_checked("./photo.jpg").unwrap(),
+        uploads.join("./photo.jpg")
+    );
+}
+
+#[test]
+fn test_join_checked_absolute_segment() {
+    let absolute = std::env::temp_dir().join("passwd");
+    match app_path!("uploads").join_checked(&absolute) {
+        Err(crate::AppPathError::AbsolutePathRejected(path)) => assert_eq!(path, absolute),
+        other => panic!("Expected AbsolutePathRejected, got: {other:?}"),
+    }
+
+    // Root-relative, which `join` would also let replace the path
+    assert!(matches!(
+        app_path!("uploads").join_checked(format!("{}etc", std::path::MAIN_SEPARATOR)),
+        Err(crate::AppPathError::AbsolutePathRejected(_))
+    ));
+}
+
+#[test]
+fn test_join_checked_parent_dir_segment() {
+    let uploads = app_path!("uploads");
+    for segment in ["..", "../config.toml", "2024/../../config.toml", "2024/.."] {
+        match uploads.join_checked(segment) {
+            Err(crate::AppPathError::PathEscapesBase { base, .. }) => {
+                assert_eq!(base, uploads.to_path_buf());
+            }
+            other => panic!("Expected PathEscapesBase for {segment:?}, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_push_multiple_segments() {
+    let mut path = app_path!("data");
+    path.push("users");
+    path.push(String::from("2024"));
+    path.push(std::path::Path::new("profile.json"));
+    assert_eq!(path, app_path!("data/users/2024/profile.json"));
+}
+
+#[test]
+fn test_push_absolute_segment_replaces() {
+    let absolute = std::env::temp_dir().join("push_absolute");
+    let mut path = app_path!("data/users");
+    path.push(&absolute);
+    assert_eq!(&*path, absolute.as_path());
+
+    path.push("nested.txt");
+    assert_eq!(&*path, absolute.join("nested.txt").as_path());
+}
+
+#[test]
+fn test_push_matches_repeated_join() {
+    let segments = ["a", "b", "c", "d.txt"];
+
+    let mut pushed = app_path!("root");
+    let mut joined = app_path!("root");
+    for segment in segments {
+        pushed.push(segment);
+        joined = joined.join(segment);
+    }
+    assert_eq!(pushed, joined);
+}
+
+#[test]
+fn test_pop_file_and_directory() {
+    let mut path = app_path!("data/users/profile.json");
+
+    // Popping a file leaves its directory
+    assert!(path.pop());
+    assert_eq!(path, app_path!("data/users"));
+
+    // Popping a directory leaves its parent
+    assert!(path.pop());
+    assert_eq!(path, app_path!("data"));
+    assert!(path.pop());
+    assert_eq!(path, AppPath::new());
+
+    // The base directory is not a boundary
+    assert!(path.pop());
+    assert_eq!(Some(path), AppPath::new().parent());
+}
+
+#[test]
+fn test_pop_at_filesystem_root() {
+    let mut path = app_path!("data");
+    while path.pop() {}
+
+    assert!(path.parent().is_none());
+    let root = path.clone();
+    assert!(!path.pop());
+    assert_eq!(path, root);
+}
+
 #[test]
 fn test_with_file_name() {
     let original = app_path!("config.toml");
-    let renamed = AppPath::with(original.with_file_name("settings.toml"));
+    let renamed = original.with_file_name("settings.toml");
     assert!(renamed.ends_with("settings.toml"));
     assert!(!renamed.ends_with("config.toml"));
 
@@ -85,6 +241,97 @@ fn test_with_file_name() {
     assert_eq!(original.parent(), renamed.parent());
 }
 
+#[test]
+fn test_with_file_name_nested_and_extension() {
+    let original = app_path!("config/deep/app.toml");
+
+    // Parent directory is stable
+    let renamed = original.with_file_name("backup.toml");
+    assert_eq!(renamed, app_path!("config/deep/backup.toml"));
+    assert_eq!(renamed.parent(), original.parent());
+
+    // The extension comes from the new name only
+    let no_ext = original.with_file_name("backup");
+    assert_eq!(no_ext.extension(), None);
+    let kept = original.with_file_name(format!(
+        "backup.{}",
+        original.extension().unwrap().to_string_lossy()
+    ));
+    assert_eq!(kept.extension(), original.extension());
+
+    // Chains stay uniform
+    let chained = original.with_file_name("log.txt").with_extension("bak");
+    assert_eq!(chained, app_path!("config/deep/log.bak"));
+}
+
+#[test]
+fn test_sibling_with_parent() {
+    let log = app_path!("logs/app.log");
+    let rotated = log.sibling("app.log.1");
+    assert_eq!(rotated, app_path!("logs/app.log.1"));
+    assert_eq!(rotated.parent(), log.parent());
+    assert_eq!(log.sibling("app.log.1"), log.with_file_name("app.log.1"));
+}
+
+#[test]
+fn test_sibling_root_level_file() {
+    let root = std::env::temp_dir()
+        .ancestors()
+        .last()
+        .unwrap()
+        .to_path_buf();
+    let root_file = AppPath::with(root.join("app.log"));
+    assert_eq!(&*root_file.sibling("app.log.1"), root.join("app.log.1"));
+
+    // A path without a parent resolves the name against the base directory
+    let bare_root = AppPath::with(&root);
+    assert_eq!(bare_root.sibling("app.log.1"), app_path!("app.log.1"));
+}
+
+#[test]
+fn test_sibling_multi_component_name() {
+    let log = app_path!("logs/app.log");
+    assert_eq!(
+        log.sibling("archive/2024/app.log.1"),
+        app_path!("logs/archive/2024/app.log.1")
+    );
+}
+
+#[test]
+fn test_watch_targets_file_and_parent() {
+    let config = app_path!("config/app.toml");
+    let (file, dir) = config.watch_targets();
+    assert_eq!(file, config);
+    assert_eq!(dir, app_path!("config"));
+    assert_eq!(Some(dir), config.parent());
+}
+
+#[test]
+fn test_watch_targets_base_level_file() {
+    let config = app_path!("config.toml");
+    let (file, dir) = config.watch_targets();
+    assert_eq!(file, config);
+    assert_eq!(dir, AppPath::new());
+
+    // Without a parent the base directory is watched
+    let root = AppPath::with(std::env::temp_dir().ancestors().last().unwrap());
+    assert_eq!(root.watch_targets(), (root.clone(), AppPath::new()));
+}
+
+#[test]
+fn test_with_file_name_matches_std_edge_cases() {
+    for input in ["config/app.toml", "config/..", "config/.", "app.toml"] {
+        let original = app_path!(input);
+        for name in ["x.txt", ""] {
+            assert_eq!(
+                &*original.with_file_name(name),
+                original.to_path_buf().with_file_name(name).as_path(),
+                "{input:?} with {name:?}"
+            );
+        }
+    }
+}
+
 #[test]
 fn test_with_extension() {
     let toml_file = app_path!("config.toml");
@@ -97,6 +344,50 @@ fn test_with_extension() {
     assert!(with_ext.ends_with("README.md"));
 }
 
+#[test]
+fn test_has_extension_matching() {
+    let json = app_path!("data/users.json");
+    assert!(json.has_extension("json"));
+    assert!(json.has_extension(".json"));
+    assert!(!json.has_extension("toml"));
+    assert!(!json.has_extension("son"));
+}
+
+#[test]
+fn test_has_extension_case_insensitive() {
+    assert!(app_path!("photo.JPG").has_extension("jpg"));
+    assert!(app_path!("photo.jpg").has_extension("JPG"));
+    assert!(app_path!("Photo.JpG").has_extension(".jPg"));
+}
+
+#[test]
+fn test_has_extension_missing() {
+    assert!(!app_path!("README").has_extension("md"));
+    assert!(!app_path!("data/").has_extension("json"));
+    assert!(!app_path!("config.toml").has_extension(""));
+    assert!(!app_path!("config.toml").has_extension("."));
+
+    // Hidden files have no extension, matching `Path::extension()`
+    let hidden = app_path!(".json");
+    assert_eq!(hidden.extension(), None);
+    assert!(!hidden.has_extension("json"));
+}
+
+#[test]
+fn test_has_any_extension_multi_part_suffix() {
+    let archive = app_path!("downloads/release-1.2.tar.gz");
+    assert!(archive.has_extension("gz"));
+    assert!(archive.has_extension("tar.gz"));
+    assert!(archive.has_any_extension(&["tar.gz", "tgz"]));
+    assert!(app_path!("release.tgz").has_any_extension(&["tar.gz", "tgz"]));
+    assert!(app_path!("RELEASE.TAR.GZ").has_any_extension(&["tar.gz"]));
+
+    // A suffix must start at a dot boundary
+    assert!(!app_path!("release.star.gz").has_extension("tar.gz"));
+    assert!(!app_path!("release.zip").has_any_extension(&["tar.gz", "tgz"]));
+    assert!(!archive.has_any_extension(&[]));
+}
+
 // === Path Comparison and Relationships ===
 
 #[test]
@@ -238,7 +529,10 @@ fn test_complex_path_building() {
 fn test_path_normalization() {
     // Test that redundant path components are handled
     let path = app_path!("config/../config/app.toml");
-    let normalized = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let normalized = path
+        .canonicalize()
+        .map(AppPath::into_path_buf)
+        .unwrap_or_else(|_| path.to_path_buf());
 
     // Should still be valid and end with the expected file
     assert!(normalized.ends_with("config/app.toml") || normalized.ends_with("config\\app.toml"));
@@ -274,6 +568,122 @@ fn test_windows_path_separators() {
     assert_eq!(forward_slash_path.file_name(), backslash_path.file_name());
 }
 
+#[cfg(windows)]
+#[test]
+fn test_windows_normalize_prefix_drive_spellings() {
+    let expected = AppPath::with(r"C:\app\config.toml");
+    for spelling in [
+        r"C:\app\config.toml",
+        r"c:\app\config.toml",
+        r"\\?\C:\app\config.toml",
+        r"\\?\c:\app\config.toml",
+        r"\\localhost\C$\app\config.toml",
+        r"\\127.0.0.1\c$\app\config.toml",
+    ] {
+        assert_eq!(
+            AppPath::with(spelling).normalize_prefix(),
+            expected,
+            "{spelling} should normalize to the drive form"
+        );
+    }
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_normalize_prefix_unc_spellings() {
+    let plain = AppPath::with(r"\\server\share\app\config.toml").normalize_prefix();
+    let verbatim = AppPath::with(r"\\?\UNC\server\share\app\config.toml").normalize_prefix();
+
+    assert_eq!(plain, verbatim);
+    assert_eq!(plain, AppPath::with(r"\\server\share\app\config.toml"));
+    // Remote shares stay UNC; only local admin shares map to drives
+    assert_ne!(
+        AppPath::with(r"\\server\C$\app").normalize_prefix(),
+        AppPath::with(r"C:\app")
+    );
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_normalize_prefix_enables_strip_prefix() {
+    let base = AppPath::with(r"\\?\C:\app").normalize_prefix();
+    let file = AppPath::with(r"c:\app\data\users.db").normalize_prefix();
+
+    assert!(file.starts_with(&base));
+    assert_eq!(
+        file.strip_prefix(&base).unwrap(),
+        std::path::Path::new(r"data\users.db")
+    );
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_is_unc() {
+    assert!(AppPath::with(r"\\server\share\config.toml").is_unc());
+    assert!(AppPath::with(r"\\?\UNC\server\share\config.toml").is_unc());
+    assert!(!AppPath::with(r"C:\app\config.toml").is_unc());
+    assert!(!AppPath::with(r"\\?\C:\app\config.toml").is_unc());
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_is_verbatim() {
+    assert!(AppPath::with(r"\\?\C:\app\config.toml").is_verbatim());
+    assert!(AppPath::with(r"\\?\UNC\server\share\config.toml").is_verbatim());
+    assert!(!AppPath::with(r"C:\app\config.toml").is_verbatim());
+    assert!(!AppPath::with(r"\\server\share\config.toml").is_verbatim());
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_strip_verbatim() {
+    assert_eq!(
+        AppPath::with(r"\\?\C:\app\config.toml").strip_verbatim(),
+        AppPath::with(r"C:\app\config.toml")
+    );
+    assert_eq!(
+        AppPath::with(r"\\?\UNC\server\share\app\config.toml").strip_verbatim(),
+        AppPath::with(r"\\server\share\app\config.toml")
+    );
+
+    // Drive letter case is preserved, unlike normalize_prefix()
+    let lower = AppPath::with(r"\\?\c:\app").strip_verbatim();
+    assert_eq!(lower.as_os_str(), std::ffi::OsStr::new(r"c:\app"));
+
+    // Plain paths are unchanged
+    let plain = AppPath::with(r"C:\app\config.toml");
+    assert_eq!(plain.strip_verbatim(), plain);
+    assert!(!AppPath::with(r"\\?\C:\app").strip_verbatim().is_verbatim());
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_display_normalized_mixed_separators() {
+    let mixed = AppPath::with(r"C:\app/config\sub/file.toml");
+    let rendered = mixed.display_normalized();
+
+    assert_eq!(rendered, r"C:\app\config\sub\file.toml");
+    assert!(!rendered.contains('/'));
+
+    // Display-only: the stored path keeps its original spelling
+    assert_eq!(mixed, AppPath::with(r"C:\app/config\sub/file.toml"));
+
+    let relative = AppPath::with("data/users\\1.json").display_normalized();
+    assert!(relative.ends_with(r"data\users\1.json"));
+    assert!(!relative.contains('/'));
+}
+
+#[cfg(windows)]
+#[test]
+fn test_windows_display_portable_forward_slashes() {
+    let path = AppPath::with(r"C:\app\config\sub/file.toml");
+    assert_eq!(path.display_portable(), "C:/app/config/sub/file.toml");
+
+    let relative = AppPath::with(r"data\users\1.json").display_portable();
+    assert!(relative.ends_with("data/users/1.json"));
+    assert!(!relative.contains('\\'));
+}
+
 #[cfg(unix)]
 #[test]
 fn test_unix_path_separators() {
@@ -282,6 +692,28 @@ fn test_unix_path_separators() {
     assert_eq!(path.file_name(), Some(OsStr::new("app.toml")));
 }
 
+#[cfg(unix)]
+#[test]
+fn test_unix_display_normalized_keeps_backslashes() {
+    // `\` is a valid file name character on Unix
+    let path = AppPath::with("data/odd\\name.txt");
+    assert_eq!(path.display_normalized(), path.to_string_lossy());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_unix_display_portable_forward_slashes() {
+    let path = AppPath::with("/srv/app/config/file.toml");
+    assert_eq!(path.display_portable(), "/srv/app/config/file.toml");
+
+    let relative = AppPath::with("data/users/1.json").display_portable();
+    assert!(relative.ends_with("data/users/1.json"));
+
+    // `\` is a file name character on Unix, not a separator
+    let odd = AppPath::with("data/odd\\name.txt").display_portable();
+    assert!(odd.ends_with("data/odd\\name.txt"));
+}
+
 // === Edge Cases ===
 
 #[test]
@@ -297,7 +729,7 @@ fn test_root_file_manipulation() {
     assert!(json_version.ends_with("app.json"));
 
     // Should be able to rename
-    let renamed = AppPath::with(root_file.with_file_name("settings.toml"));
+    let renamed = root_file.with_file_name("settings.toml");
     assert!(renamed.ends_with("settings.toml"));
     assert_eq!(renamed.parent(), root_file.parent());
 }
@@ -353,6 +785,30 @@ fn test_into_path_buf_equivalence() {
     assert!(via_into_path_buf.ends_with("config.toml"));
 }
 
+#[test]
+fn test_as_cow_borrows_internal_path() {
+    use std::borrow::Cow;
+
+    let app_path = app_path!("config.toml");
+    match app_path.as_cow() {
+        Cow::Borrowed(path) => assert!(std::ptr::eq(path, &*app_path)),
+        Cow::Owned(_) => panic!("as_cow() must not allocate"),
+    }
+}
+
+#[test]
+fn test_into_cow_matches_into_path_buf() {
+    use std::borrow::Cow;
+
+    let app_path = app_path!("config/settings/app.toml");
+    let expected = app_path.clone().into_path_buf();
+
+    match app_path.into_cow() {
+        Cow::Owned(path) => assert_eq!(path, expected),
+        Cow::Borrowed(_) => panic!("into_cow() must return an owned path"),
+    }
+}
+
 #[test]
 fn test_into_inner_with_nested_path() {
     let app_path = app_path!("config/settings/app.toml");
@@ -504,6 +960,62 @@ fn test_to_bytes_with_special_chars() {
     assert_ne!(bytes, no_spaces.to_bytes());
 }
 
+#[test]
+fn test_bytes_portable_uses_forward_slashes() {
+    let nested = AppPath::with(Path::new("data").join("sub").join("users.db"));
+    assert_eq!(nested.bytes_portable(), b"data/sub/users.db");
+    assert_eq!(AppPath::new().bytes_portable(), b"");
+}
+
+#[test]
+fn test_bytes_portable_round_trip() {
+    for input in ["data/users.db", "config.toml", "a/./b", "data/../logs", ""] {
+        let original = app_path!(input);
+        let restored = AppPath::from_bytes_portable(&original.bytes_portable()).unwrap();
+        assert_eq!(restored, original, "round trip of {input:?}");
+    }
+
+    // Stored `/` separators resolve to the native form on every platform
+    let from_slashes = AppPath::from_bytes_portable(b"data/sub/users.db").unwrap();
+    assert_eq!(from_slashes.bytes_portable(), b"data/sub/users.db");
+    assert_eq!(from_slashes, app_path!("data").join("sub").join("users.db"));
+}
+
+#[test]
+fn test_bytes_portable_non_ascii() {
+    let path = app_path!("données/résumé 履歴.txt");
+    let bytes = path.bytes_portable();
+    assert_eq!(bytes, "données/résumé 履歴.txt".as_bytes());
+    assert_eq!(AppPath::from_bytes_portable(&bytes).unwrap(), path);
+}
+
+#[test]
+fn test_bytes_portable_outside_base_is_absolute() {
+    let outside = PathBuf::from(format!("{}_elsewhere", AppPath::new().display())).join("users.db");
+    let path = AppPath::with(&outside);
+    let bytes = path.bytes_portable();
+    assert_eq!(bytes, path.display_portable().into_bytes());
+    assert_eq!(AppPath::from_bytes_portable(&bytes).unwrap(), path);
+
+    let escaped = app_path!("../shared/users.db");
+    assert_eq!(
+        AppPath::from_bytes_portable(&escaped.bytes_portable()).unwrap(),
+        escaped
+    );
+}
+
+#[test]
+fn test_from_bytes_portable_rejects_invalid_input() {
+    assert!(matches!(
+        AppPath::from_bytes_portable(&[b'a', 0xff, b'b']),
+        Err(crate::AppPathError::InvalidPath { .. })
+    ));
+    assert!(matches!(
+        AppPath::from_bytes_portable(b"data/\0users.db".as_slice()),
+        Err(crate::AppPathError::InvalidPath { .. })
+    ));
+}
+
 #[test]
 fn test_into_bytes_basic() {
     let path = app_path!("config.toml");
@@ -672,3 +1184,176 @@ fn test_bytes_cross_platform_compatibility() {
     let bytes3 = path3.to_bytes();
     assert_eq!(complex_bytes, bytes3);
 }
+
+// === Temporary Sibling Paths ===
+
+#[test]
+fn test_temp_sibling_shares_parent() {
+    let target = app_path!("data/users.db");
+    let temp = target.temp_sibling();
+
+    assert_eq!(temp.parent(), target.parent());
+    assert_ne!(temp, target);
+    let name = temp.file_name().unwrap().to_string_lossy().into_owned();
+    assert!(name.starts_with(".users.db."));
+    assert!(name.ends_with(".tmp"));
+    assert!(!temp.exists());
+}
+
+#[test]
+fn test_temp_sibling_unique_across_calls() {
+    use std::collections::HashSet;
+
+    let target = app_path!("config.toml");
+    let names: HashSet<_> = (0..1000).map(|_| target.temp_sibling()).collect();
+    assert_eq!(names.len(), 1000);
+}
+
+#[test]
+fn test_temp_sibling_unique_across_threads() {
+    use std::collections::HashSet;
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            std::thread::spawn(|| {
+                let target = app_path!("shared.txt");
+                (0..100).map(|_| target.temp_sibling()).collect::<Vec<_>>()
+            })
+        })
+        .collect();
+
+    let all: HashSet<_> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(all.len(), 800);
+}
+
+#[test]
+fn test_temp_in_parent_shares_parent_and_is_unique() {
+    use std::collections::HashSet;
+
+    let target = app_path!("data/users.db");
+    let temps: HashSet<_> = (0..1000)
+        .map(|_| target.temp_in_parent().unwrap())
+        .collect();
+
+    assert_eq!(temps.len(), 1000);
+    for temp in &temps {
+        assert_eq!(temp.parent(), target.parent());
+        assert!(!temp.exists());
+    }
+}
+
+#[test]
+fn test_temp_in_parent_rejects_root() {
+    let root = AppPath::with(AppPath::new().ancestors().last().unwrap());
+
+    match root.temp_in_parent() {
+        Err(crate::AppPathError::InvalidPath { path, .. }) => assert_eq!(path, root.to_path_buf()),
+        other => panic!("Expected InvalidPath, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_rotated_names() {
+    let log = AppPath::with("logs/app.log");
+    assert_eq!(log.rotated(0), log);
+    assert_eq!(log.rotated(1), AppPath::with("logs/app.1.log"));
+    assert_eq!(log.rotated(10), AppPath::with("logs/app.10.log"));
+
+    let bare = AppPath::with("journal");
+    assert_eq!(bare.rotated(3), AppPath::with("journal.3"));
+
+    let archive = AppPath::with("backup.tar.gz");
+    assert_eq!(archive.rotated(1), AppPath::with("backup.tar.1.gz"));
+}
+
+#[test]
+fn test_resolve_request_legitimate_paths() {
+    let public = AppPath::with("public");
+
+    assert_eq!(
+        public.resolve_request("index.html").unwrap(),
+        AppPath::with("public/index.html")
+    );
+    assert_eq!(
+        public.resolve_request("/assets/css/site.css").unwrap(),
+        AppPath::with("public/assets/css/site.css")
+    );
+    assert_eq!(
+        public
+            .resolve_request("docs/v1.2/..hidden-but-fine")
+            .unwrap(),
+        AppPath::with("public/docs/v1.2/..hidden-but-fine")
+    );
+}
+
+#[test]
+fn test_resolve_request_rejects_traversal() {
+    use crate::AppPathError;
+
+    let public = AppPath::with("public");
+    let attempts = [
+        "",
+        "/",
+        "..",
+        "../secret.txt",
+        "assets/css/../../etc/passwd",
+        "assets/../../../etc/passwd",
+        "./index.html",
+        "assets/./site.css",
+        "assets//site.css",
+        "assets/",
+        "//etc/passwd",
+        "..\\..\\windows\\win.ini",
+        "assets\\..\\..\\secret",
+        "C:/windows/win.ini",
+        "C:secret",
+        "file.txt:stream",
+        "nul\0byte",
+    ];
+
+    for attempt in attempts {
+        match public.resolve_request(attempt) {
+            Err(AppPathError::PathEscapesBase { base, .. }) => {
+                assert_eq!(base, public.to_path_buf(), "wrong base for {attempt:?}");
+            }
+            other => panic!("{attempt:?} must be rejected, got: {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn test_fs_eq_trailing_and_repeated_separators() {
+    let plain = AppPath::with("data/cache");
+    assert!(plain.fs_eq(&AppPath::with("data/cache/")));
+    assert!(plain.fs_eq(&AppPath::with("data//cache")));
+    assert!(plain.fs_eq(&AppPath::with("data/./cache")));
+    assert!(plain.fs_eq(&plain));
+
+    assert!(!plain.fs_eq(&AppPath::with("data/cache2")));
+    assert!(!plain.fs_eq(&AppPath::with("data")));
+    assert!(!plain.fs_eq(&AppPath::with("data/cache/more")));
+}
+
+#[test]
+fn test_fs_eq_case_follows_platform() {
+    let lower = AppPath::with("config/app.toml");
+    let upper = AppPath::with("CONFIG/App.TOML");
+
+    assert_eq!(lower.fs_eq(&upper), cfg!(any(windows, target_os = "macos")));
+    // Strict equality is never case-insensitive
+    assert_ne!(lower, upper);
+}
+
+#[cfg(windows)]
+#[test]
+fn test_fs_eq_windows_separators() {
+    let forward = AppPath::with(r"C:/app/config/app.toml");
+    let back = AppPath::with(r"C:\app\config\app.toml");
+    let mixed = AppPath::with(r"c:\APP/config\app.toml\");
+
+    assert!(forward.fs_eq(&back));
+    assert!(back.fs_eq(&mixed));
+}
@@ -0,0 +1,97 @@
+use super::support::{is_isolated, run_isolated};
+use crate::{AbsolutePolicy, AppPath, AppPathError};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+fn absolute_input() -> PathBuf {
+    std::env::temp_dir().join("app_path_policy.toml")
+}
+
+#[test]
+fn test_default_policy_is_allow() {
+    assert_eq!(AppPath::absolute_policy(), AbsolutePolicy::Allow);
+    assert_eq!(AbsolutePolicy::default(), AbsolutePolicy::Allow);
+}
+
+#[test]
+fn test_absolute_policy_allow() {
+    run_isolated("tests::policy::isolated_absolute_policy_allow");
+}
+
+#[test]
+fn test_absolute_policy_reject() {
+    run_isolated("tests::policy::isolated_absolute_policy_reject");
+}
+
+#[test]
+fn test_absolute_policy_warn() {
+    run_isolated("tests::policy::isolated_absolute_policy_warn");
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_absolute_policy_allow"]
+fn isolated_absolute_policy_allow() {
+    if !is_isolated() {
+        return;
+    }
+    AppPath::set_absolute_policy(AbsolutePolicy::Reject);
+    AppPath::set_absolute_policy(AbsolutePolicy::Allow);
+
+    let path = AppPath::try_with(absolute_input()).unwrap();
+    assert_eq!(&*path, absolute_input().as_path());
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_absolute_policy_reject"]
+fn isolated_absolute_policy_reject() {
+    if !is_isolated() {
+        return;
+    }
+    AppPath::set_absolute_policy(AbsolutePolicy::Reject);
+    assert_eq!(AppPath::absolute_policy(), AbsolutePolicy::Reject);
+
+    // Relative inputs are unaffected
+    assert!(AppPath::try_with("config.toml").is_ok());
+
+    match AppPath::try_with(absolute_input()) {
+        Err(AppPathError::AbsolutePathRejected(path)) => assert_eq!(path, absolute_input()),
+        other => panic!("Expected AbsolutePathRejected, got: {other:?}"),
+    }
+
+    // Overrides go through the same check
+    assert!(AppPath::try_with_override("config.toml", Some(absolute_input())).is_err());
+    assert!(std::panic::catch_unwind(|| AppPath::with(absolute_input())).is_err());
+
+    // Path operations on existing instances are unaffected
+    let config = AppPath::with("config/app.toml");
+    assert!(config.parent().is_some());
+    assert!(config.with_extension("json").is_absolute());
+}
+
+static OBSERVED: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+fn record(path: &Path) {
+    OBSERVED.lock().unwrap().push(path.to_path_buf());
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_absolute_policy_warn"]
+fn isolated_absolute_policy_warn() {
+    if !is_isolated() {
+        return;
+    }
+    AppPath::set_absolute_observer(Some(record));
+    AppPath::set_absolute_policy(AbsolutePolicy::Warn);
+
+    let relative = AppPath::try_with("config.toml").unwrap();
+    let absolute = AppPath::try_with(absolute_input()).unwrap();
+
+    assert!(relative.is_absolute());
+    assert_eq!(&*absolute, absolute_input().as_path());
+    assert_eq!(*OBSERVED.lock().unwrap(), vec![absolute_input()]);
+
+    // Without an observer, absolute inputs are allowed silently
+    AppPath::set_absolute_observer(None);
+    assert!(AppPath::try_with(absolute_input()).is_ok());
+    assert_eq!(OBSERVED.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,95 @@
+use crate::{AppPath, AppPathError, RelativeAppPath};
+use std::path::Path;
+
+#[test]
+fn test_strip_base() {
+    let db = AppPath::with("data/users.db");
+    let relative = db.strip_base().unwrap();
+
+    assert_eq!(relative.as_path(), Path::new("data/users.db"));
+    assert_eq!(Some(relative.as_path()), db.relative_to_base());
+    assert_eq!(relative.resolve(), db);
+    assert_eq!(relative.try_resolve().unwrap(), db);
+
+    // The base directory itself strips to an empty path
+    let base = AppPath::new().strip_base().unwrap();
+    assert_eq!(base.as_path(), Path::new(""));
+    assert_eq!(base.resolve(), AppPath::new());
+}
+
+#[test]
+fn test_strip_base_outside_base() {
+    let root = std::env::temp_dir()
+        .ancestors()
+        .last()
+        .unwrap()
+        .to_path_buf();
+    let outside = AppPath::with(root.join("app_path_outside_base.txt"));
+    if !outside.starts_with(AppPath::new()) {
+        assert_eq!(outside.strip_base(), None);
+    }
+}
+
+#[test]
+fn test_relative_display_uses_forward_slashes() {
+    let relative = AppPath::with("data/users/1.json").strip_base().unwrap();
+    assert_eq!(relative.to_string(), "data/users/1.json");
+
+    let restored = RelativeAppPath::try_new(relative.to_string()).unwrap();
+    assert_eq!(restored, relative);
+}
+
+#[test]
+fn test_relative_try_new_rejects_absolute() {
+    let absolute = std::env::temp_dir().join("config.toml");
+    match RelativeAppPath::try_new(&absolute) {
+        Err(AppPathError::AbsolutePathRejected(path)) => assert_eq!(path, absolute),
+        other => panic!("Expected AbsolutePathRejected, got: {other:?}"),
+    }
+    assert!(RelativeAppPath::try_new("plugins/audio.so").is_ok());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_relative_serde_round_trip() {
+    let relative = AppPath::with("data/users.db").strip_base().unwrap();
+
+    let json = serde_json::to_string(&relative).unwrap();
+    assert_eq!(json, r#""data/users.db""#);
+
+    let restored: RelativeAppPath = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, relative);
+    assert_eq!(restored.resolve(), AppPath::with("data/users.db"));
+
+    // Absolute values are rejected on the way in
+    let absolute = serde_json::to_string(&std::env::temp_dir()).unwrap();
+    assert!(serde_json::from_str::<RelativeAppPath>(&absolute).is_err());
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_relative_resolves_against_new_base() {
+    super::support::run_isolated("tests::relative::isolated_relative_resolves_against_new_base");
+}
+
+#[cfg(feature = "testing")]
+#[test]
+#[ignore = "mutates process-global state; run via test_relative_resolves_against_new_base"]
+fn isolated_relative_resolves_against_new_base() {
+    if !super::support::is_isolated() {
+        return;
+    }
+    let relative = AppPath::with("data/users.db").strip_base().unwrap();
+    let stored = relative.to_string();
+
+    // Simulate moving the installation to another machine
+    let other_base = std::env::temp_dir().join("app_path_test_relative_other_base");
+    AppPath::set_exe_dir_for_testing(other_base.clone());
+
+    let restored = RelativeAppPath::try_new(stored).unwrap();
+    assert_eq!(&*restored.resolve(), other_base.join("data/users.db"));
+    assert_eq!(
+        restored.resolve().strip_base().unwrap().as_path(),
+        Path::new("data/users.db")
+    );
+}
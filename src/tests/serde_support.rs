@@ -0,0 +1,96 @@
+use crate::AppPath;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct AppConfig {
+    data_dir: Option<String>,
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[test]
+fn test_with_override_from_config_field_present() {
+    let config = AppConfig {
+        data_dir: Some("custom/data".to_string()),
+        name: "myapp".to_string(),
+    };
+
+    let data = AppPath::with_override_from_config("data", &config, |c| c.data_dir.as_deref());
+    assert_eq!(data, AppPath::with("custom/data"));
+
+    let absolute = std::env::temp_dir().join("config_data_dir");
+    let config = AppConfig {
+        data_dir: Some(absolute.to_string_lossy().into_owned()),
+        name: "myapp".to_string(),
+    };
+    let data =
+        AppPath::try_with_override_from_config("data", &config, |c| c.data_dir.as_deref()).unwrap();
+    assert_eq!(&*data, absolute.as_path());
+}
+
+#[test]
+fn test_with_override_from_config_field_omitted() {
+    let config = AppConfig {
+        data_dir: None,
+        name: "myapp".to_string(),
+    };
+
+    let data = AppPath::with_override_from_config("data", &config, |c| c.data_dir.as_deref());
+    assert_eq!(data, AppPath::with("data"));
+
+    let data =
+        AppPath::try_with_override_from_config("data", &config, |c| c.data_dir.as_deref()).unwrap();
+    assert_eq!(data, AppPath::with("data"));
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct PathsConfig {
+    data_dir: AppPath,
+    log_file: Option<AppPath>,
+}
+
+#[test]
+fn test_serde_relative_resolves_against_base() {
+    let config: PathsConfig =
+        serde_json::from_str(r#"{ "data_dir": "data", "log_file": "logs/app.log" }"#).unwrap();
+    assert_eq!(config.data_dir, AppPath::with("data"));
+    assert_eq!(config.log_file, Some(AppPath::with("logs/app.log")));
+
+    // Serialization emits the resolved path, which round-trips unchanged
+    let json = serde_json::to_string(&config).unwrap();
+    let expected = serde_json::to_string(&AppPath::with("data").to_path_buf()).unwrap();
+    assert!(json.contains(&expected));
+    let round_trip: PathsConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_trip, config);
+}
+
+#[test]
+fn test_serde_absolute_passes_through() {
+    let absolute = std::env::temp_dir().join("serde_absolute_data");
+    let json = serde_json::to_string(&absolute).unwrap();
+
+    let path: AppPath = serde_json::from_str(&json).unwrap();
+    assert_eq!(&*path, absolute.as_path());
+    assert_eq!(serde_json::to_string(&path).unwrap(), json);
+}
+
+#[test]
+fn test_serde_windows_style_paths() {
+    let path: AppPath = serde_json::from_str(r#""config\\app.toml""#).unwrap();
+    assert_eq!(path, AppPath::with("config\\app.toml"));
+    let round_trip: AppPath = serde_json::from_str(&serde_json::to_string(&path).unwrap()).unwrap();
+    assert_eq!(round_trip, path);
+
+    // A drive-letter path is absolute on Windows and relative elsewhere; either way it
+    // resolves exactly like `AppPath::with()`
+    let drive: AppPath = serde_json::from_str(r#""C:\\ProgramData\\app""#).unwrap();
+    assert_eq!(drive, AppPath::with(r"C:\ProgramData\app"));
+    if cfg!(windows) {
+        assert_eq!(&*drive, std::path::Path::new(r"C:\ProgramData\app"));
+    }
+}
+
+#[test]
+fn test_serde_rejects_non_string() {
+    assert!(serde_json::from_str::<AppPath>("42").is_err());
+}
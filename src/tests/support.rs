@@ -0,0 +1,32 @@
+//! Helpers for tests that mutate process-global state.
+//!
+//! Settings such as the absolute-path policy are process-wide, so changing them
+//! in-process would leak into tests running concurrently on other threads. Such
+//! tests are marked `#[ignore]` and re-run by a regular test in a child process.
+
+use std::process::Command;
+
+const ISOLATED_ENV: &str = "APP_PATH_ISOLATED_TEST";
+
+/// Returns true when running inside a child process spawned by [`run_isolated`].
+///
+/// Isolated tests return early otherwise, so `cargo test -- --ignored` stays harmless.
+pub fn is_isolated() -> bool {
+    std::env::var_os(ISOLATED_ENV).is_some()
+}
+
+/// Runs the ignored test `name` (full path, e.g. `tests::policy::reject`) in a child process.
+pub fn run_isolated(name: &str) {
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args([name, "--exact", "--ignored", "--test-threads=1"])
+        .env(ISOLATED_ENV, "1")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        output.status.success() && stdout.contains("1 passed"),
+        "isolated test {name} failed:\n{stdout}\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,95 @@
+use super::support::{is_isolated, run_isolated};
+use crate::{app_path, AppPath};
+use std::env;
+
+#[test]
+fn test_set_exe_dir_for_testing() {
+    run_isolated("tests::testing::isolated_set_exe_dir_for_testing");
+}
+
+#[test]
+fn test_clear_exe_dir_override() {
+    run_isolated("tests::testing::isolated_clear_exe_dir_override");
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_set_exe_dir_for_testing"]
+fn isolated_set_exe_dir_for_testing() {
+    if !is_isolated() {
+        return;
+    }
+    let sandbox = env::temp_dir().join("app_path_test_injected_base");
+    AppPath::set_exe_dir_for_testing(sandbox.clone());
+
+    assert_eq!(&*AppPath::new(), sandbox.as_path());
+    assert_eq!(&*AppPath::with("config.toml"), sandbox.join("config.toml"));
+    assert_eq!(&*app_path!("data/users.db"), sandbox.join("data/users.db"));
+    assert_eq!(
+        AppPath::with("logs/app.log").relative_component_count(),
+        Some(2)
+    );
+
+    // A later override replaces the earlier one
+    let other = env::temp_dir().join("app_path_test_injected_base_2");
+    AppPath::set_exe_dir_for_testing(other.clone());
+    assert_eq!(&*AppPath::with("config.toml"), other.join("config.toml"));
+}
+
+#[test]
+#[ignore = "mutates process-global state; run via test_clear_exe_dir_override"]
+fn isolated_clear_exe_dir_override() {
+    if !is_isolated() {
+        return;
+    }
+    let real_base = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let sandbox = env::temp_dir().join("app_path_test_injected_clear");
+
+    AppPath::set_exe_dir_for_testing(sandbox.clone());
+    assert_eq!(&*AppPath::with("a.txt"), sandbox.join("a.txt"));
+
+    AppPath::clear_exe_dir_override();
+    assert_eq!(&*AppPath::with("a.txt"), real_base.join("a.txt"));
+
+    // Clearing twice is harmless
+    AppPath::clear_exe_dir_override();
+    assert_eq!(&*AppPath::new(), real_base.as_path());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_use_canonical_base_resolves_symlinked_override() {
+    run_isolated("tests::testing::isolated_use_canonical_base_resolves_symlinked_override");
+}
+
+#[cfg(unix)]
+#[test]
+#[ignore = "mutates process-global state; run via test_use_canonical_base_resolves_symlinked_override"]
+fn isolated_use_canonical_base_resolves_symlinked_override() {
+    if !is_isolated() {
+        return;
+    }
+    let temp_dir = env::temp_dir().join("app_path_test_canonical_base");
+    let _ = std::fs::remove_dir_all(&temp_dir);
+    let real = temp_dir.join("real");
+    let link = temp_dir.join("link");
+    std::fs::create_dir_all(&real).unwrap();
+    std::os::unix::fs::symlink(&real, &link).unwrap();
+    let canonical = real.canonicalize().unwrap();
+
+    AppPath::set_exe_dir_for_testing(link.clone());
+    assert_eq!(&*AppPath::with("config.toml"), link.join("config.toml"));
+
+    assert!(AppPath::use_canonical_base().unwrap());
+    assert_eq!(&*AppPath::new(), canonical.as_path());
+    assert_eq!(
+        &*AppPath::with("config.toml"),
+        canonical.join("config.toml")
+    );
+
+    // Idempotent
+    assert!(AppPath::use_canonical_base().unwrap());
+    assert_eq!(&*AppPath::new(), canonical.as_path());
+
+    AppPath::clear_exe_dir_override();
+    std::fs::remove_dir_all(&temp_dir).ok();
+}
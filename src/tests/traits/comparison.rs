@@ -21,9 +21,79 @@ fn test_partial_eq_different_paths() {
 fn test_partial_eq_with_normalization() {
     let path1 = AppPath::with("config.toml");
     let path2 = AppPath::with("./config.toml");
-    // These might be equal after normalization, depending on implementation
-    // The exact behavior depends on how the library handles path normalization
-    let _ = path1 == path2; // Just verify it compiles and doesn't panic
+    // The strict comparison may or may not consider these equal, depending on how the
+    // platform joins `.`; `eq_normalized` always does
+    let _ = path1 == path2;
+    assert!(path1.eq_normalized(&path2));
+}
+
+#[test]
+fn test_eq_normalized_equivalent_spellings() {
+    let spellings = [
+        AppPath::with("config.toml"),
+        AppPath::with("./config.toml"),
+        AppPath::with("foo/../config.toml"),
+        AppPath::with("foo/./bar/../../config.toml"),
+    ];
+    for a in &spellings {
+        for b in &spellings {
+            assert!(a.eq_normalized(b), "{a} vs {b}");
+        }
+    }
+
+    assert!(!AppPath::with("config.toml").eq_normalized(&AppPath::with("foo/config.toml")));
+    assert!(!AppPath::with("config.toml").eq_normalized(&AppPath::with("../config.toml")));
+}
+
+#[test]
+fn test_hash_normalized_consistent_with_eq_normalized() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    fn normalized_hash(path: &AppPath) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        path.hash_normalized(&mut hasher);
+        hasher.finish()
+    }
+
+    let plain = normalized_hash(&AppPath::with("config.toml"));
+    assert_eq!(plain, normalized_hash(&AppPath::with("./config.toml")));
+    assert_eq!(plain, normalized_hash(&AppPath::with("foo/../config.toml")));
+    assert_ne!(plain, normalized_hash(&AppPath::with("settings.toml")));
+
+    // Default `PartialEq` stays strict
+    assert_ne!(
+        AppPath::with("config.toml"),
+        AppPath::with("foo/../config.toml")
+    );
+}
+
+#[test]
+fn test_eq_ignore_case_same_case() {
+    let path = AppPath::with("data/config.toml");
+    assert!(path.eq_ignore_case(&AppPath::with("data/config.toml")));
+}
+
+#[test]
+fn test_eq_ignore_case_different_case_same_path() {
+    let upper = AppPath::with("Data/Config.TOML");
+    let lower = AppPath::with("data/config.toml");
+    assert!(upper.eq_ignore_case(&lower));
+    assert!(lower.eq_ignore_case(&upper));
+
+    // Default `PartialEq` and `Ord` stay exact
+    assert_ne!(upper, lower);
+    assert_ne!(upper.cmp(&lower), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn test_eq_ignore_case_different_paths() {
+    let config = AppPath::with("config.toml");
+    assert!(!config.eq_ignore_case(&AppPath::with("settings.toml")));
+    assert!(!config.eq_ignore_case(&AppPath::with("data/config.toml")));
+
+    // Only ASCII letters are folded
+    assert!(!AppPath::with("Ärger.txt").eq_ignore_case(&AppPath::with("ärger.txt")));
 }
 
 // === Hash Trait Tests ===
@@ -31,6 +31,27 @@ fn test_as_ref_path_with_nested() {
     );
 }
 
+// === AsRef<AppPath> Trait Tests ===
+
+#[test]
+fn test_as_ref_app_path_generic() {
+    fn storage_key<P: AsRef<AppPath>>(path: P) -> String {
+        path.as_ref().storage_key()
+    }
+
+    let app_path = AppPath::with("data/users.db");
+    let borrowed = storage_key(&app_path);
+    let owned = storage_key(app_path.clone());
+    assert_eq!(borrowed, owned);
+
+    // The existing conversions keep resolving by target type
+    let path_ref: &Path = app_path.as_ref();
+    let os_ref: &std::ffi::OsStr = app_path.as_ref();
+    let self_ref: &AppPath = app_path.as_ref();
+    assert_eq!(path_ref.as_os_str(), os_ref);
+    assert!(std::ptr::eq(self_ref, &app_path));
+}
+
 // === Into<PathBuf> Trait Tests ===
 
 #[test]
@@ -129,3 +150,36 @@ fn test_works_with_std_functions() {
     let joined = app_path.join("subfile.txt");
     assert!(joined.to_string_lossy().contains("test_file.txt"));
 }
+
+// === TryFrom<OsString> / TryFrom<&OsStr> Tests ===
+
+#[test]
+fn test_try_from_os_string() {
+    use std::ffi::{OsStr, OsString};
+
+    let owned = AppPath::try_from(OsString::from("data/input.csv")).unwrap();
+    assert_eq!(owned, AppPath::with("data/input.csv"));
+
+    let borrowed = AppPath::try_from(OsStr::new("data/input.csv")).unwrap();
+    assert_eq!(borrowed, owned);
+
+    // Absolute inputs pass through like `try_with`
+    let absolute = std::env::temp_dir().join("try_from_os_string.txt");
+    let converted: AppPath = absolute.clone().into_os_string().try_into().unwrap();
+    assert_eq!(&*converted, absolute.as_path());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_try_from_non_utf8_os_string() {
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::{OsStrExt, OsStringExt};
+
+    let bytes = b"report_\xff\xfe.bin".to_vec();
+    let owned = AppPath::try_from(OsString::from_vec(bytes.clone())).unwrap();
+    let borrowed = AppPath::try_from(OsStr::from_bytes(&bytes)).unwrap();
+
+    assert_eq!(owned, borrowed);
+    assert_eq!(owned.file_name().unwrap().as_bytes(), bytes.as_slice());
+    assert!(owned.to_str().is_none());
+}
@@ -1,4 +1,4 @@
-use crate::AppPath;
+use crate::{AppPath, AppPathShared};
 use std::sync::Arc;
 
 // === Clone Trait Tests ===
@@ -23,6 +23,52 @@ fn test_clone_independence() {
     assert_eq!(original.parent(), cloned.parent());
 }
 
+#[test]
+fn test_shared_clone_shares_storage() {
+    let shared = AppPath::with("reports/2024/q4/summary.pdf").into_shared();
+    let cloned = shared.clone();
+
+    assert!(AppPathShared::ptr_eq(&shared, &cloned));
+    assert_eq!(shared, cloned);
+
+    // Equal paths converted separately do not share storage
+    let separate = AppPath::with("reports/2024/q4/summary.pdf").into_shared();
+    assert_eq!(shared, separate);
+    assert!(!AppPathShared::ptr_eq(&shared, &separate));
+}
+
+#[test]
+fn test_shared_conversions_round_trip() {
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+
+    let original = AppPath::with("data/users.db");
+    let shared: AppPathShared = original.clone().into();
+
+    assert_eq!(shared, original);
+    assert_eq!(original, shared);
+    assert_eq!(shared.to_string(), original.to_string());
+    assert_eq!(shared.file_name(), Some("users.db".as_ref()));
+    assert_eq!(AppPath::from(shared.clone()), original);
+    assert_eq!(PathBuf::from(shared.clone()), original.to_path_buf());
+
+    let set: HashSet<AppPathShared> = [shared.clone()].into_iter().collect();
+    assert!(set.contains(original.as_ref() as &Path));
+}
+
+#[test]
+fn test_shared_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AppPathShared>();
+
+    let shared = AppPath::with("shared.txt").into_shared();
+    let handle = {
+        let shared = shared.clone();
+        std::thread::spawn(move || shared.to_path_buf())
+    };
+    assert_eq!(handle.join().unwrap(), shared.to_path_buf());
+}
+
 // === Send and Sync Traits Tests ===
 
 #[test]
@@ -0,0 +1,57 @@
+use crate::AppPath;
+
+#[test]
+fn test_typed_native_round_trips_text() {
+    let dist = AppPath::with("dist/app.bin");
+    let native = dist.to_typed_native();
+
+    assert_eq!(native.to_string_lossy(), dist.to_string_lossy());
+    assert!(native.is_absolute());
+    assert!(native.ends_with("app.bin"));
+}
+
+#[test]
+fn test_typed_windows_uses_backslashes() {
+    let dist = AppPath::with("dist/sub/app.exe");
+    let windows = dist.to_typed_windows();
+    let text = windows.to_string_lossy();
+
+    assert!(text.ends_with(r"dist\sub\app.exe"), "{text}");
+    assert!(!text.contains('/'));
+    let names: Vec<_> = windows.file_name().into_iter().collect();
+    assert_eq!(names, [b"app.exe".as_slice()]);
+}
+
+#[test]
+fn test_typed_unix_uses_forward_slashes() {
+    let dist = AppPath::with("dist/sub/app");
+    let unix = dist.to_typed_unix();
+    let text = unix.to_string_lossy();
+
+    assert!(text.ends_with("dist/sub/app"), "{text}");
+    assert!(text.starts_with('/'));
+    assert!(!text.contains('\\'));
+}
+
+#[test]
+fn test_typed_conversions_keep_components() {
+    let path = AppPath::with("data/users/1.json");
+    let expected: Vec<String> = path
+        .components()
+        .filter_map(|component| match component {
+            std::path::Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+
+    for typed in [
+        path.to_typed_windows().to_string_lossy().into_owned(),
+        path.to_typed_unix().to_string_lossy().into_owned(),
+    ] {
+        let names: Vec<&str> = typed
+            .split(['/', '\\'])
+            .filter(|name| !name.is_empty() && !name.ends_with(':'))
+            .collect();
+        assert_eq!(names, expected, "{typed}");
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{AppPath, AppPathError};
+
+#[test]
+fn test_utf8_conversion_ascii_path() {
+    let assets = AppPath::with("web/assets/style.css");
+
+    let borrowed = assets.to_utf8().unwrap();
+    assert_eq!(borrowed.as_std_path(), &*assets);
+
+    let owned = assets.clone().try_into_utf8().unwrap();
+    assert_eq!(owned, borrowed);
+    assert!(owned.as_str().ends_with("style.css"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_utf8_conversion_non_utf8_path() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let name = OsStr::from_bytes(b"invalid_\xff\xfe.txt");
+    let path = AppPath::with(name);
+
+    assert!(path.to_utf8().is_none());
+    match path.clone().try_into_utf8() {
+        Err(AppPathError::NonUtf8Path(rejected)) => assert_eq!(rejected, path.to_path_buf()),
+        other => panic!("Expected NonUtf8Path, got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_non_utf8_path_error_display() {
+    let error = AppPathError::NonUtf8Path("data/file".into());
+    assert!(error.to_string().contains("not valid UTF-8"));
+    assert!(error.to_string().contains("file"));
+}
@@ -0,0 +1,95 @@
+//! Allocation benchmarks for the `cache` feature.
+//!
+//! Lives in its own test binary because it installs a counting global allocator.
+#![cfg(feature = "cache")]
+
+use app_path::{app_path, AppPathShared};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+// Counts allocations made by the current thread, so parallel tests don't interfere
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: usize = 1000;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn cached_with_still_allocates_its_own_path() {
+    drop(app_path!("templates/cache_bench_index.html"));
+
+    // An AppPath owns its PathBuf, so a cache hit still copies the path once
+    let cached = allocations_during(|| {
+        for _ in 0..ITERATIONS {
+            drop(app_path!("templates/cache_bench_index.html"));
+        }
+    });
+
+    assert_eq!(cached, ITERATIONS, "cache hits allocated {cached} times");
+}
+
+#[test]
+fn cached_shared_hit_does_not_allocate() {
+    let input = "templates/cache_bench_shared.html";
+    let warm = AppPathShared::with(input);
+
+    let cached = allocations_during(|| {
+        for _ in 0..ITERATIONS {
+            let shared = AppPathShared::with(input);
+            assert!(AppPathShared::ptr_eq(&shared, &warm));
+        }
+    });
+
+    assert_eq!(cached, 0, "cache hits allocated {cached} times");
+}
+
+#[test]
+fn cached_shared_hits_across_threads() {
+    let input = "templates/cache_bench_threads.html";
+    let warm = AppPathShared::with(input);
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let warm = warm.clone();
+            std::thread::spawn(move || {
+                allocations_during(|| {
+                    for _ in 0..ITERATIONS {
+                        let shared = AppPathShared::with(input);
+                        assert!(AppPathShared::ptr_eq(&shared, &warm));
+                    }
+                })
+            })
+        })
+        .collect();
+
+    for handle in handles {
+        assert_eq!(handle.join().unwrap(), 0);
+    }
+}